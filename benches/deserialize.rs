@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use holiday_event_api::model::GetEventInfoResponse;
+
+/// The event info fixture with many years' worth of occurrences, as returned for a wide range.
+fn large_event_info() -> String {
+    let fixture = std::fs::read_to_string("testdata/getEventInfo-default.json").unwrap();
+    let occurrences: Vec<String> = (1..=5000)
+        .map(|year| format!(r#"{{"date": "08/08/{year:04}", "length": 1}}"#))
+        .collect();
+    let start = fixture.find("\"occurrences\"").unwrap();
+    let end = fixture[start..].find(']').unwrap() + start + 1;
+    format!(
+        "{}\"occurrences\": [{}]{}",
+        &fixture[..start],
+        occurrences.join(","),
+        &fixture[end..]
+    )
+}
+
+fn deserialize(c: &mut Criterion) {
+    let small = std::fs::read_to_string("testdata/getEventInfo-default.json").unwrap();
+    let large = large_event_info();
+
+    c.bench_function("event_info_default", |b| {
+        b.iter(|| serde_json::from_str::<GetEventInfoResponse>(black_box(&small)).unwrap())
+    });
+    c.bench_function("event_info_5000_occurrences", |b| {
+        b.iter(|| serde_json::from_str::<GetEventInfoResponse>(black_box(&large)).unwrap())
+    });
+}
+
+criterion_group!(benches, deserialize);
+criterion_main!(benches);
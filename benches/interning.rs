@@ -0,0 +1,112 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use holiday_event_api::{
+    model::{EventSummary, GetEventsResponse},
+    InternedSummary, Interner,
+};
+
+/// Counts the bytes currently allocated, to measure what a month of responses holds on to.
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn summary(id: usize) -> String {
+    format!(
+        r#"{{"id": "{id:032x}", "name": "Event {id}", "url": "https://www.checkiday.com/{id:032x}/event-{id}"}}"#
+    )
+}
+
+/// A month of get_events responses: 20 single-day Events a day, plus 15 of 20 multi-day Events
+/// that are ongoing all month.
+fn month() -> Vec<String> {
+    (0..30)
+        .map(|day| {
+            let events: Vec<String> = (0..20).map(|i| summary(1000 + day * 20 + i)).collect();
+            let ongoing: Vec<String> = (0..15).map(|i| summary((day + i) % 20)).collect();
+            format!(
+                r#"{{"adult": false, "date": "05/{:02}/2025", "timezone": "America/Chicago", "events": [{}], "multiday_starting": [], "multiday_ongoing": [{}]}}"#,
+                day + 1,
+                events.join(","),
+                ongoing.join(",")
+            )
+        })
+        .collect()
+}
+
+/// The summaries of the responses in `month`.
+fn parse(month: &[String]) -> Vec<EventSummary> {
+    month
+        .iter()
+        .flat_map(|json| {
+            let response: GetEventsResponse = serde_json::from_str(json).unwrap();
+            response.events.into_iter().chain(response.multiday_ongoing)
+        })
+        .collect()
+}
+
+fn intern(interner: &Interner, summaries: Vec<EventSummary>) -> Vec<InternedSummary> {
+    summaries
+        .into_iter()
+        .map(|summary| interner.summary(summary))
+        .collect()
+}
+
+/// The bytes the summaries of `month` hold on to, without and with interned ids.
+fn measure(month: &[String]) -> (usize, usize) {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let summaries = parse(month);
+    let plain = ALLOCATED.load(Ordering::Relaxed) - before;
+    // Includes the interner itself.
+    let interner = Interner::new();
+    let interned = intern(&interner, summaries);
+    let interned_bytes = ALLOCATED.load(Ordering::Relaxed) - before;
+    drop(interned);
+    (plain, interned_bytes)
+}
+
+fn interning(c: &mut Criterion) {
+    let month = month();
+
+    // The same month fetched in 4 timezones, where interning pays off.
+    let timezones: Vec<String> = month
+        .iter()
+        .cycle()
+        .take(month.len() * 4)
+        .cloned()
+        .collect();
+    let (plain, interned) = measure(&timezones);
+    assert!(
+        interned < plain,
+        "{plain} bytes, {interned} bytes with interned ids"
+    );
+
+    c.bench_function("parse_month", |b| b.iter(|| parse(black_box(&month))));
+    c.bench_function("parse_and_intern_month", |b| {
+        b.iter(|| {
+            let interner = Interner::new();
+            intern(&interner, parse(black_box(&month)))
+        })
+    });
+}
+
+criterion_group!(benches, interning);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use holiday_event_api::model::RateLimit;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+/// Headers resembling a real response's, with the Rate Limit ones last.
+fn headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in [
+        ("content-type", "application/json; charset=utf-8"),
+        ("content-length", "1024"),
+        ("date", "Thu, 16 Oct 2025 12:00:00 GMT"),
+        ("server", "nginx"),
+        ("x-ratelimit-limit-day", "10000"),
+        ("x-ratelimit-remaining-day", "9876"),
+        ("x-ratelimit-limit-month", "100000"),
+        ("x-ratelimit-remaining-month", "98765"),
+    ] {
+        headers.insert(name, HeaderValue::from_static(value));
+    }
+    headers
+}
+
+fn rate_limit(c: &mut Criterion) {
+    let headers = headers();
+
+    c.bench_function("rate_limit_parsed", |b| {
+        b.iter(|| RateLimit::from_headers(black_box(&headers)).unwrap_or_default())
+    });
+    // What parse_rate_limit(false) does instead.
+    c.bench_function("rate_limit_skipped", |b| {
+        b.iter(|| {
+            black_box(&headers);
+            RateLimit::default()
+        })
+    });
+}
+
+criterion_group!(benches, rate_limit);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+use chrono::Local;
+use holiday_event_api::HolidayEventApi;
+
+#[tokio::main]
+async fn main() {
+    // Get a FREE API key from https://apilayer.com/marketplace/checkiday-api#pricing
+    let client = match HolidayEventApi::new("<your API key>") {
+        Ok(client) => client,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    // International Cat Day
+    let id = "f90b893ea04939d7456f30c54f68d7b4";
+    match client.days_until_next(id, Local::now().date_naive()).await {
+        Ok(Some((0, _))) => println!("International Cat Day is today!"),
+        Ok(Some((days, occurrence))) => {
+            println!(
+                "International Cat Day is in {} days ({:?})",
+                days, occurrence.date
+            )
+        }
+        Ok(None) => println!("International Cat Day isn't coming up."),
+        Err(e) => println!("{}", e),
+    }
+}
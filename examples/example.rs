@@ -6,31 +6,36 @@ use holiday_event_api::{
 #[tokio::main]
 async fn main() {
     // Get a FREE API key from https://apilayer.com/marketplace/checkiday-api#pricing
-    let client = HolidayEventApi::new("<your API key>");
+    let client = match HolidayEventApi::new("<your API key>") {
+        Ok(client) => client,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
 
-    if client.is_err() {
-        println!("{}", client.unwrap_err());
-        return;
-    }
-
-    let client = client.unwrap();
     // Get Events for a given Date
-    let events = client
+    let events = match client
         .get_events(GetEventsRequest {
             // These parameters are all optional. These are their defaults:
             date: Some("today".into()),
             adult: Some(false),
             timezone: Some("America/Chicago".into()),
+            ..Default::default()
         })
-        .await;
+        .await
+    {
+        Ok(events) => events,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
 
-    if events.is_err() {
-        println!("{}", events.unwrap_err());
+    let Some(event) = events.events.first() else {
+        println!("No Events found.");
         return;
-    }
-
-    let events = events.unwrap();
-    let event = events.events.get(0).unwrap();
+    };
     println!(
         "Today is {}! Find more information at: {}.",
         event.name, event.url
@@ -41,44 +46,49 @@ async fn main() {
     );
 
     // Get Event Information
-    let event_info = client
+    let event_info = match client
         .get_event_info(GetEventInfoRequest {
             id: event.id.to_string(),
             // These parameters can be specified to calculate the range of event_info.event.occurrences
             start: None, // Some(2020),
             end: None,   // Some(2030),
+            ..Default::default()
         })
-        .await;
-
-    if event_info.is_err() {
-        println!("{}", event_info.unwrap_err());
-        return;
-    }
-
-    let event_info = event_info.unwrap();
+        .await
+    {
+        Ok(event_info) => event_info,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
 
     println!("The Event's hashtags are {:?}.", event_info.event.hashtags);
 
     // Search for Events
     let query = "pizza day";
-    let search = client
+    let search = match client
         .search(SearchRequest {
             query: query.into(),
             // These parameters are the defaults but can be specified:
             adult: None, // Some(true),
+            ..Default::default()
         })
-        .await;
+        .await
+    {
+        Ok(search) => search,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
 
-    if search.is_err() {
-        println!("{}", search.unwrap_err());
-        return;
+    if let Some(first) = search.events.first() {
+        println!(
+            "Found {} events, including {}, that match the query \"{}\".",
+            search.events.len(),
+            first.name,
+            query
+        );
     }
-
-    let search = search.unwrap();
-    println!(
-        "Found {} events, including {}, that match the query \"{}\".",
-        search.events.len(),
-        search.events.first().unwrap().name,
-        query
-    )
 }
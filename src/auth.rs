@@ -0,0 +1,69 @@
+use reqwest::{
+    header::{self, HeaderValue},
+    RequestBuilder,
+};
+
+/// How the API key is sent with each request. Defaults to
+/// [`ApiLayerHeader`](AuthScheme::ApiLayerHeader).
+///
+/// Set it with [`ClientBuilder::auth_scheme`](crate::ClientBuilder::auth_scheme).
+/// The keys themselves come from [`HolidayEventApi::builder`](crate::HolidayEventApi::builder)
+/// or [`ClientBuilder::api_keys`](crate::ClientBuilder::api_keys), so failing
+/// over between keys works with every scheme.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum AuthScheme {
+    /// In an `apikey` header, as apilayer expects
+    #[default]
+    ApiLayerHeader,
+    /// In an `Authorization: Bearer <key>` header
+    BearerToken,
+    /// In the query parameter `name`
+    ///
+    /// The key then shows up in the URL of each request, e.g. in proxy logs.
+    QueryParam {
+        /// The query parameter's name
+        name: String,
+    },
+}
+
+impl AuthScheme {
+    /// The value sent for `key`, marked sensitive so it isn't printed.
+    pub(crate) fn credential(&self, key: &str) -> Option<HeaderValue> {
+        let mut value = match self {
+            AuthScheme::BearerToken => HeaderValue::try_from(format!("Bearer {key}")),
+            _ => HeaderValue::try_from(key),
+        }
+        .ok()?;
+        value.set_sensitive(true);
+        Some(value)
+    }
+
+    /// Adds `credential`, from [`credential`](Self::credential), to `req`.
+    pub(crate) fn apply(&self, req: RequestBuilder, credential: &HeaderValue) -> RequestBuilder {
+        match self {
+            AuthScheme::ApiLayerHeader => req.header("apikey", credential.clone()),
+            AuthScheme::BearerToken => req.header(header::AUTHORIZATION, credential.clone()),
+            AuthScheme::QueryParam { name } => {
+                // Keys are validated to be visible ASCII, so this can't fail.
+                req.query(&[(name.as_str(), credential.to_str().unwrap_or_default())])
+            }
+        }
+    }
+
+    /// The query parameter carrying the key, if any.
+    pub(crate) fn query_param(&self) -> Option<&str> {
+        match self {
+            AuthScheme::QueryParam { name } => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Drops the URL from `error` if it may contain the key.
+    pub(crate) fn redact(&self, error: reqwest::Error) -> reqwest::Error {
+        match self {
+            AuthScheme::QueryParam { .. } => error.without_url(),
+            _ => error,
+        }
+    }
+}
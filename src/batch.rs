@@ -0,0 +1,218 @@
+use std::{collections::HashMap, future::Future, time::Instant};
+
+use futures_util::{future, stream, StreamExt, TryStreamExt};
+
+use crate::{model, Error, HolidayEventApi};
+
+impl HolidayEventApi {
+    /// Runs several searches concurrently and merges their Events.
+    ///
+    /// At most `concurrency` searches are in flight at once. Events are
+    /// deduplicated by id and listed in order of first appearance, following
+    /// the order of `queries`. Failed searches are collected in
+    /// [`MergedSearch::errors`](model::MergedSearch::errors) instead of
+    /// failing the whole batch. Each query costs one request.
+    pub async fn search_many(
+        &self,
+        queries: Vec<String>,
+        adult: Option<bool>,
+        concurrency: usize,
+    ) -> Result<model::MergedSearch, Error> {
+        if queries.is_empty() {
+            return Err(Error::MissingQuery(self.messages.missing_query.clone()));
+        }
+
+        let results: Vec<(String, Result<model::SearchResponse, Error>)> = stream::iter(queries)
+            .map(|query| async move {
+                let result = self
+                    .search(model::SearchRequest {
+                        query: query.clone(),
+                        adult,
+                        ..Default::default()
+                    })
+                    .await;
+                (query, result)
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut merged = model::MergedSearch {
+            events: vec![],
+            errors: vec![],
+            rate_limit: Default::default(),
+        };
+        for (query, result) in results {
+            match result {
+                Ok(response) => {
+                    merged.rate_limit = response.rate_limit;
+                    for event in response.events {
+                        match merged.events.iter_mut().find(|m| m.event.id == event.id) {
+                            Some(existing) => existing.queries.push(query.clone()),
+                            None => merged.events.push(model::MergedEvent {
+                                event,
+                                queries: vec![query.clone()],
+                            }),
+                        }
+                    }
+                }
+                Err(e) => merged.errors.push((query, e)),
+            }
+        }
+        Ok(merged)
+    }
+
+    /// Gets today's Events, then the Event Info of each of them.
+    ///
+    /// Event Info is fetched with at most `concurrency` requests in flight at
+    /// once, and returned in the order of
+    /// [`unique_events`](model::GetEventsResponse::unique_events). This costs
+    /// one request of quota plus one per Event, i.e. often dozens. Fails with
+    /// the first error encountered.
+    pub async fn get_today_with_info(
+        &self,
+        concurrency: usize,
+    ) -> Result<Vec<model::GetEventInfoResponse>, Error> {
+        let events = self.get_events(model::GetEventsRequest::default()).await?;
+        stream::iter(events.unique_events())
+            .map(|event| {
+                self.get_event_info(model::GetEventInfoRequest {
+                    id: event.id.to_string(),
+                    ..Default::default()
+                })
+            })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await
+    }
+
+    /// Gets the Event Info for each request, one at a time, until `limits`
+    /// are hit.
+    ///
+    /// Returns the responses in order, with how far the batch got. Each
+    /// request costs one request of quota. Stops at the first error, keeping
+    /// the responses so far and the error in
+    /// [`StopReason::Failed`](model::StopReason::Failed).
+    pub async fn get_event_info_batch(
+        &self,
+        requests: Vec<model::GetEventInfoRequest>,
+        limits: model::BatchLimits,
+    ) -> (Vec<model::GetEventInfoResponse>, model::BatchOutcome) {
+        self.run_limited(requests, limits, |request| self.get_event_info(request))
+            .await
+    }
+
+    /// Gets the Events for each day from `start` to `end`, inclusive, one day
+    /// at a time, until `limits` are hit.
+    ///
+    /// Returns the responses in order, with how far the batch got. Each day
+    /// costs one request of quota. Stops at the first error, keeping the
+    /// responses so far and the error in
+    /// [`StopReason::Failed`](model::StopReason::Failed).
+    #[cfg(feature = "chrono")]
+    pub async fn get_events_range(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+        limits: model::BatchLimits,
+    ) -> (Vec<model::GetEventsResponse>, model::BatchOutcome) {
+        let requests = start
+            .iter_days()
+            .take_while(|day| *day <= end)
+            .map(|day| model::GetEventsRequest {
+                date: Some(day.format("%m/%d/%Y").to_string()),
+                ..Default::default()
+            })
+            .collect();
+        self.run_limited(requests, limits, |request| self.get_events(request))
+            .await
+    }
+
+    /// Sends each request with `send`, in order, until `limits` are hit.
+    async fn run_limited<R, T, F, Fut>(
+        &self,
+        requests: Vec<R>,
+        limits: model::BatchLimits,
+        send: F,
+    ) -> (Vec<T>, model::BatchOutcome)
+    where
+        F: Fn(R) -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        let total = requests.len();
+        let mut results = Vec::with_capacity(total);
+        let mut issued = 0;
+        let mut stop_reason = None;
+        for request in requests {
+            stop_reason = self.stop_reason(&limits);
+            if stop_reason.is_some() {
+                break;
+            }
+            issued += 1;
+            match send(request).await {
+                Ok(result) => results.push(result),
+                Err(e) => {
+                    stop_reason = Some(model::StopReason::Failed(e));
+                    break;
+                }
+            }
+        }
+        let outcome = model::BatchOutcome {
+            completed: results.len(),
+            skipped: total - issued,
+            stop_reason,
+        };
+        (results, outcome)
+    }
+
+    fn stop_reason(&self, limits: &model::BatchLimits) -> Option<model::StopReason> {
+        if limits
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return Some(model::StopReason::Deadline);
+        }
+        let rate_limit = self.last_rate_limit();
+        let known = rate_limit != model::RateLimit::default();
+        if known
+            && limits
+                .quota_floor
+                .is_some_and(|floor| rate_limit.remaining_month <= floor)
+        {
+            return Some(model::StopReason::QuotaFloor);
+        }
+        None
+    }
+
+    /// Gets the Events for the provided Date in each of the given timezones,
+    /// concurrently.
+    ///
+    /// Results are keyed by timezone. Each distinct timezone costs one
+    /// request of quota, and all of them are in flight at once.
+    pub async fn get_events_multi_tz(
+        &self,
+        date: Option<String>,
+        timezones: &[String],
+        adult: Option<bool>,
+    ) -> HashMap<String, Result<model::GetEventsResponse, Error>> {
+        let mut unique: Vec<&String> = timezones.iter().collect();
+        unique.sort();
+        unique.dedup();
+
+        let requests = unique.into_iter().map(|timezone| {
+            let date = date.clone();
+            async move {
+                let result = self
+                    .get_events(model::GetEventsRequest {
+                        date,
+                        adult,
+                        timezone: Some(timezone.as_str().into()),
+                        ..Default::default()
+                    })
+                    .await;
+                (timezone.clone(), result)
+            }
+        });
+        future::join_all(requests).await.into_iter().collect()
+    }
+}
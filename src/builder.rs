@@ -0,0 +1,593 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize},
+        Arc,
+    },
+    time::Duration,
+};
+
+use reqwest::{
+    header::{self, HeaderValue},
+    redirect, Url,
+};
+use tokio::sync::watch;
+#[cfg(feature = "cancellation")]
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    cache::EventInfoCache,
+    cache_store::CacheStore,
+    model::{RateLimit, ValidationWarning, WarningHandler},
+    quota::LowQuotaAlert,
+    retry::RetryIf,
+    AuthScheme, Endpoint, Error, HolidayEventApi, Messages, Metrics, RequestInterceptor, Sleeper,
+    APP_USER_AGENT,
+};
+#[cfg(feature = "chrono")]
+use crate::{Clock, SystemClock};
+
+/// How long a request may take.
+const TIMEOUT: Duration = Duration::from_secs(10);
+/// The API as offered by apilayer, used with [`AuthScheme::ApiLayerHeader`].
+const DEFAULT_BASE_URL: &str = "https://api.apilayer.com/checkiday/";
+
+/// Configures and builds a [`HolidayEventApi`] client.
+///
+/// Obtain one with [`HolidayEventApi::builder`].
+#[derive(Debug)]
+pub struct ClientBuilder {
+    auth_scheme: AuthScheme,
+    api_keys: Vec<String>,
+    base_url: Option<String>,
+    fallback_base_url: Option<String>,
+    http1_only: bool,
+    http2_prior_knowledge: bool,
+    pool_idle_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    accept_language: Option<String>,
+    tcp_keepalive: Option<Duration>,
+    redirect: Option<redirect::Policy>,
+    allowed_host: Option<String>,
+    event_info_cache: Option<EventInfoCache>,
+    messages: Messages,
+    default_timezone: Option<String>,
+    default_adult: bool,
+    parse_rate_limit: bool,
+    low_quota_alert: Option<LowQuotaAlert>,
+    warning_handler: Option<WarningHandler>,
+    retry_if: Option<RetryIf>,
+    max_retries: u32,
+    retry_backoff: Duration,
+    sleeper: Option<Arc<dyn Sleeper>>,
+    metrics: Option<Arc<dyn Metrics>>,
+    interceptors: Vec<Arc<dyn RequestInterceptor>>,
+    #[cfg(feature = "chrono")]
+    clock: Arc<dyn Clock>,
+    #[cfg(feature = "chrono")]
+    auto_pace: bool,
+    #[cfg(feature = "uuid")]
+    request_id_header: String,
+    #[cfg(feature = "cancellation")]
+    cancellation_token: Option<CancellationToken>,
+}
+
+impl ClientBuilder {
+    pub(crate) fn new(api_key: &str) -> Self {
+        Self {
+            auth_scheme: AuthScheme::default(),
+            api_keys: vec![api_key.into()],
+            base_url: None,
+            fallback_base_url: None,
+            http1_only: false,
+            http2_prior_knowledge: false,
+            pool_idle_timeout: None,
+            read_timeout: None,
+            accept_language: None,
+            tcp_keepalive: None,
+            redirect: None,
+            allowed_host: None,
+            event_info_cache: None,
+            messages: Messages::default(),
+            default_timezone: None,
+            default_adult: false,
+            parse_rate_limit: true,
+            low_quota_alert: None,
+            warning_handler: None,
+            retry_if: None,
+            max_retries: 1,
+            retry_backoff: Duration::from_millis(500),
+            sleeper: crate::sleep::default_sleeper(),
+            metrics: None,
+            interceptors: vec![],
+            #[cfg(feature = "chrono")]
+            clock: Arc::new(SystemClock),
+            #[cfg(feature = "chrono")]
+            auto_pace: false,
+            #[cfg(feature = "uuid")]
+            request_id_header: "X-Request-Id".into(),
+            #[cfg(feature = "cancellation")]
+            cancellation_token: None,
+        }
+    }
+
+    pub(crate) fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sends requests to the API at `base_url`, e.g. a proxy in front of it.
+    ///
+    /// Like every base URL, it must be `http` or `https` without a query or
+    /// fragment, or [`build`](Self::build) fails with
+    /// [`Error::InvalidBaseUrl`]. A trailing slash is added to its path if
+    /// missing, so endpoints are resolved under it.
+    pub fn base_url_parsed(mut self, base_url: Url) -> Self {
+        self.base_url = Some(base_url.into());
+        self
+    }
+
+    /// Sends the API key as `scheme` says. Defaults to
+    /// [`AuthScheme::ApiLayerHeader`].
+    ///
+    /// Other providers of the API use other hosts, so with any other scheme,
+    /// set theirs with [`base_url_parsed`](Self::base_url_parsed) too, or
+    /// [`build`](Self::build) fails with [`Error::MissingBaseUrl`].
+    pub fn auth_scheme(mut self, scheme: AuthScheme) -> Self {
+        self.auth_scheme = scheme;
+        self
+    }
+
+    /// Retries requests that can't connect to the API once against
+    /// `base_url`, e.g. a secondary host serving the same API.
+    ///
+    /// Other failures, such as timeouts or error responses, are not retried,
+    /// since the request may have reached the API already.
+    pub fn fallback_base_url(mut self, base_url: &str) -> Self {
+        self.fallback_base_url = Some(base_url.into());
+        self
+    }
+
+    /// Uses the given API keys, in order, replacing the one passed to
+    /// [`HolidayEventApi::builder`].
+    ///
+    /// When the active key's monthly quota is exhausted, requests are
+    /// transparently retried with the next key, which then stays active for
+    /// all clones of the client (see [`HolidayEventApi::active_key_index`]).
+    /// Once every key is exhausted, requests fail with
+    /// [`Error::QuotaExhausted`].
+    pub fn api_keys(mut self, api_keys: Vec<String>) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
+    /// Only use HTTP/1.1.
+    ///
+    /// Useful behind proxies that mishandle HTTP/2. Each in-flight request
+    /// needs its own connection, so many concurrent calls on one client
+    /// open many connections.
+    pub fn http1_only(mut self) -> Self {
+        self.http1_only = true;
+        self
+    }
+
+    /// Use HTTP/2 without negotiating it first.
+    ///
+    /// HTTP/2 multiplexes concurrent requests over a single connection, which
+    /// benefits callers that run many requests at once (e.g. joined futures).
+    /// Requests fail if the server (or a proxy in between) does not speak
+    /// HTTP/2. Over TLS, HTTP/2 is already negotiated automatically when the
+    /// server supports it, so this is mostly useful to skip that negotiation.
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http2_prior_knowledge = true;
+        self
+    }
+
+    /// How long an idle connection is kept open for reuse. Defaults to 90
+    /// seconds.
+    ///
+    /// A client idle for longer than this reconnects (including the TLS
+    /// handshake) on its next request. A longer timeout speeds up sporadic
+    /// requests, but the server or a proxy may still close the connection
+    /// earlier.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// How long to wait for each read of the response, e.g. the next chunk of
+    /// its body, in addition to the overall 10 second timeout. Unlimited by
+    /// default.
+    ///
+    /// A response that sends its headers but then stalls fails with
+    /// [`Error::BodyTimeout`] once a read takes longer than this, instead of
+    /// holding the request for the full 10 seconds.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// How often TCP keepalive probes are sent on idle connections. Defaults
+    /// to every 15 seconds.
+    ///
+    /// Probes detect connections that died while idle, so the next request
+    /// opens a new connection instead of failing on the dead one. They also
+    /// stop some firewalls and NATs from dropping idle connections.
+    pub fn tcp_keepalive(mut self, interval: Duration) -> Self {
+        self.tcp_keepalive = Some(interval);
+        self
+    }
+
+    /// Sets the redirect policy. Defaults to following up to 10 redirects.
+    ///
+    /// With [`redirect::Policy::none`], redirects are not followed and
+    /// requests fail with [`Error::Redirected`] instead.
+    pub fn redirect(mut self, policy: redirect::Policy) -> Self {
+        self.redirect = Some(policy);
+        self
+    }
+
+    /// Fails requests to any host but `host` with [`Error::NetworkForbidden`],
+    /// e.g. set to the mock server's host so tests can't reach the live API.
+    ///
+    /// Hosts are compared without their port. The URL of every request is
+    /// checked before it's sent, including with the
+    /// [`fallback_base_url`](Self::fallback_base_url). Redirects to other
+    /// hosts are not followed and fail too, while redirects to `host` follow
+    /// the [`redirect`](Self::redirect) policy.
+    pub fn forbid_network_except(mut self, host: &str) -> Self {
+        self.allowed_host = Some(host.into());
+        self
+    }
+
+    /// Memoizes get_event_info responses in memory for `ttl`, keeping at most
+    /// `max_entries` of them in a [`MemoryCacheStore`](crate::MemoryCacheStore).
+    /// Disabled by default.
+    ///
+    /// Responses are cached under their request's
+    /// [`cache_key`](crate::model::GetEventInfoRequest::cache_key), i.e. per
+    /// `(id, start, end)`, and requests with `extra_params` bypass the cache.
+    /// Each range is cached whole, details included: the API has no endpoint
+    /// for Occurrences alone, so another range always costs a full request.
+    /// Expiry is checked with the client's [clock](Self::with_clock). On a
+    /// cache hit, the response's `rate_limit` reflects the most recent live
+    /// request made by this client (see [`HolidayEventApi::last_rate_limit`]).
+    ///
+    /// Replaces any [`cache_store`](Self::cache_store).
+    pub fn event_info_cache(mut self, ttl: Duration, max_entries: usize) -> Self {
+        self.event_info_cache = Some(EventInfoCache::in_memory(ttl, max_entries));
+        self
+    }
+
+    /// Caches get_event_info responses in `store` for `ttl`, like
+    /// [`event_info_cache`](Self::event_info_cache) but e.g. on disk (see
+    /// [`DirCacheStore`](crate::DirCacheStore)) to keep them across restarts.
+    /// Disabled by default.
+    ///
+    /// Replaces any [`event_info_cache`](Self::event_info_cache).
+    pub fn cache_store(mut self, store: Arc<dyn CacheStore>, ttl: Duration) -> Self {
+        self.event_info_cache = Some(EventInfoCache::in_store(store, ttl));
+        self
+    }
+
+    /// Overrides the messages of errors detected by this crate, e.g. to
+    /// localize them. Defaults to English.
+    pub fn messages(mut self, messages: Messages) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// The timezone get_events uses when the request doesn't set one, instead
+    /// of the API's default (America/Chicago).
+    pub fn default_timezone(mut self, timezone: &str) -> Self {
+        self.default_timezone = Some(timezone.into());
+        self
+    }
+
+    /// Whether get_events and search include adult Events when the request
+    /// doesn't say. Defaults to false.
+    pub fn default_adult(mut self, adult: bool) -> Self {
+        self.default_adult = adult;
+        self
+    }
+
+    /// Whether to read the Rate Limit from every response's headers. Defaults
+    /// to true.
+    ///
+    /// When disabled, responses' `rate_limit` and
+    /// [`HolidayEventApi::last_rate_limit`] stay at their default, which saves
+    /// a little work per request. Failing over to the next API key (see
+    /// [`api_keys`](Self::api_keys)) still works.
+    pub fn parse_rate_limit(mut self, parse: bool) -> Self {
+        self.parse_rate_limit = parse;
+        self
+    }
+
+    /// Calls `callback` with the Rate Limit when the remaining monthly quota
+    /// drops below `threshold`, e.g. to warn before requests start failing.
+    ///
+    /// It's called once per drop, not for every response below the threshold,
+    /// and not at all when [`parse_rate_limit`](Self::parse_rate_limit) is
+    /// disabled. It runs on the requesting task, so it should return quickly.
+    pub fn low_quota_threshold(
+        mut self,
+        threshold: i32,
+        callback: impl Fn(RateLimit) + Send + Sync + 'static,
+    ) -> Self {
+        self.low_quota_alert = Some(LowQuotaAlert::new(threshold, callback));
+        self
+    }
+
+    /// Retries a failed request, up to [`max_retries`](Self::max_retries)
+    /// times, if `predicate` returns true for its error, e.g. only for
+    /// `Error::Api { status: 503, .. }`. Disabled by default, so failed
+    /// requests aren't retried (apart from switching API keys and using the
+    /// [`fallback_base_url`](Self::fallback_base_url)).
+    ///
+    /// Each retry first waits as long as the failed response's `Retry-After`
+    /// header asks, or else [`retry_backoff`](Self::retry_backoff). Waiting
+    /// uses the client's [`sleeper`](Self::sleeper), so [`build`](Self::build)
+    /// fails with [`Error::MissingSleeper`] without one. Retries count
+    /// against the quota like any other request.
+    pub fn retry_if(mut self, predicate: Arc<dyn Fn(&Error) -> bool + Send + Sync>) -> Self {
+        self.retry_if = Some(RetryIf::new(predicate));
+        self
+    }
+
+    /// How many times [`retry_if`](Self::retry_if) may retry a request.
+    /// Defaults to 1.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// How long to wait before the first retry of a request, doubling before
+    /// each further one, unless the response says how long with
+    /// `Retry-After`. Defaults to 500 milliseconds.
+    pub fn retry_backoff(mut self, backoff: Duration) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Validates every response (see e.g. [`GetEventsResponse::validate`](crate::model::GetEventsResponse::validate)),
+    /// calling `callback` with the warnings of each response that has any,
+    /// e.g. to log them. Responses are returned as usual either way.
+    ///
+    /// It runs on the requesting task, so it should return quickly.
+    pub fn validate_responses(
+        mut self,
+        callback: impl Fn(Endpoint, &[ValidationWarning]) + Send + Sync + 'static,
+    ) -> Self {
+        self.warning_handler = Some(WarningHandler::new(callback));
+        self
+    }
+
+    /// Reports every request to `metrics`, labeled with its
+    /// [`Endpoint`](crate::Endpoint).
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Adds an interceptor that may inspect or change every request before
+    /// it's sent, e.g. [`LoggingInterceptor`](crate::LoggingInterceptor).
+    pub fn interceptor(mut self, interceptor: Arc<dyn RequestInterceptor>) -> Self {
+        self.interceptors.push(interceptor);
+        self
+    }
+
+    /// Sets the clock used for everything time-dependent, e.g. cache expiry
+    /// and relative year ranges. Defaults to [`SystemClock`].
+    #[cfg(feature = "chrono")]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Whether to wait before each request so the remaining monthly quota
+    /// lasts until the month ends. Defaults to false.
+    ///
+    /// The wait is the [`RateLimit::suggested_interval`](crate::model::RateLimit::suggested_interval)
+    /// of [`HolidayEventApi::last_rate_limit`], and is skipped while the
+    /// quota is unknown or exhausted. Waiting uses the client's
+    /// [`sleeper`](Self::sleeper), so [`build`](Self::build) fails with
+    /// [`Error::MissingSleeper`] without one. Concurrent requests each wait
+    /// on their own, so pacing holds best for sequential requests.
+    #[cfg(feature = "chrono")]
+    pub fn auto_pace(mut self, auto_pace: bool) -> Self {
+        self.auto_pace = auto_pace;
+        self
+    }
+
+    /// Sets what the client waits on, e.g. before [retrying](Self::retry_if)
+    /// requests. Defaults to [`TokioSleeper`](crate::TokioSleeper) with the
+    /// `tokio-time` feature, else to
+    /// [`FuturesTimerSleeper`](crate::FuturesTimerSleeper) with the
+    /// `async-std-compat` feature, else to none.
+    pub fn sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = Some(sleeper);
+        self
+    }
+
+    /// Sets the header carrying each request's generated id. Defaults to
+    /// `X-Request-Id`.
+    ///
+    /// See [`HolidayEventApi::last_request_id`].
+    #[cfg(feature = "uuid")]
+    pub fn request_id_header(mut self, name: &str) -> Self {
+        self.request_id_header = name.into();
+        self
+    }
+
+    /// Sends `language` as the `Accept-Language` header of every request,
+    /// e.g. `de` or `fr-CA, fr;q=0.9`.
+    ///
+    /// The API doesn't document any localized content yet, so responses may
+    /// stay in English. Text fields, such as names and descriptions, are the
+    /// ones a localized response would differ in. An invalid value fails
+    /// [`build`](Self::build) with [`Error::InvalidHeaderValue`].
+    pub fn accept_language(mut self, language: &str) -> Self {
+        self.accept_language = Some(language.into());
+        self
+    }
+
+    /// Cancels requests of the client, and all its clones, once `token` is
+    /// cancelled, failing them with [`Error::Cancelled`].
+    ///
+    /// Unlike [`HolidayEventApi::close`], requests already in flight are
+    /// cancelled too, e.g. to shut down right away.
+    #[cfg(feature = "cancellation")]
+    pub fn cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Builds the client.
+    #[must_use = "the client is dropped right away, and errors go unnoticed"]
+    pub fn build(self) -> Result<HolidayEventApi, Error> {
+        let api_keys: Option<Vec<HeaderValue>> = self
+            .api_keys
+            .iter()
+            .map(|key| {
+                HolidayEventApi::validate_key(key).ok()?;
+                self.auth_scheme.credential(key)
+            })
+            .collect();
+        let Some(api_keys) = api_keys.filter(|keys| !keys.is_empty()) else {
+            return Err(Error::InvalidApiKey(self.messages.invalid_api_key));
+        };
+        #[cfg(feature = "chrono")]
+        if self.auto_pace && self.sleeper.is_none() {
+            return Err(Error::MissingSleeper);
+        }
+        if self.retry_if.is_some() && self.sleeper.is_none() {
+            return Err(Error::MissingSleeper);
+        }
+        let mut headers = header::HeaderMap::new();
+        let rustc = rustc_version_runtime::version();
+        headers.insert(
+            "X-Platform-Version",
+            HeaderValue::try_from(&rustc.to_string()).unwrap(),
+        );
+        if let Some(language) = self.accept_language {
+            let Ok(value) = HeaderValue::try_from(&language) else {
+                return Err(Error::InvalidHeaderValue(language));
+            };
+            headers.insert(header::ACCEPT_LANGUAGE, value);
+        }
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .user_agent(APP_USER_AGENT)
+            .timeout(TIMEOUT);
+
+        if self.http1_only {
+            builder = builder.http1_only();
+        }
+
+        if self.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+
+        if let Some(interval) = self.tcp_keepalive {
+            builder = builder.tcp_keepalive(interval);
+        }
+
+        if let Some(host) = self.allowed_host.clone() {
+            // Stopped redirects are reported as forbidden by the client.
+            let policy = self.redirect.unwrap_or_default();
+            builder = builder.redirect(redirect::Policy::custom(move |attempt| {
+                if attempt.url().host_str() != Some(&host) {
+                    attempt.stop()
+                } else {
+                    policy.redirect(attempt)
+                }
+            }));
+        } else if let Some(policy) = self.redirect {
+            builder = builder.redirect(policy);
+        }
+
+        let Ok(client) = builder.build() else {
+            return Err(Error::ClientBuild);
+        };
+
+        let base_url = match (&self.base_url, &self.auth_scheme) {
+            (Some(base_url), _) => base_url.as_str(),
+            (None, AuthScheme::ApiLayerHeader) => DEFAULT_BASE_URL,
+            (None, _) => return Err(Error::MissingBaseUrl),
+        };
+        let base_url = parse_base_url(base_url)?;
+        let fallback_base_url = self
+            .fallback_base_url
+            .as_deref()
+            .map(parse_base_url)
+            .transpose()?;
+
+        #[cfg(feature = "uuid")]
+        let Ok(request_id_header) = header::HeaderName::try_from(&self.request_id_header) else {
+            return Err(Error::InvalidHeaderName(self.request_id_header));
+        };
+
+        Ok(HolidayEventApi {
+            client,
+            auth_scheme: self.auth_scheme,
+            base_url,
+            fallback_base_url,
+            api_keys: api_keys.into(),
+            active_key: Arc::new(AtomicUsize::new(0)),
+            timeout: TIMEOUT,
+            read_timeout: self.read_timeout,
+            allowed_host: self.allowed_host,
+            event_info_cache: self.event_info_cache,
+            last_rate_limit: Arc::new(watch::Sender::new(RateLimit::default())),
+            messages: Arc::new(self.messages),
+            closed: Arc::new(AtomicBool::new(false)),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            default_timezone: self.default_timezone,
+            default_adult: self.default_adult,
+            parse_rate_limit: self.parse_rate_limit,
+            low_quota_alert: self.low_quota_alert,
+            warning_handler: self.warning_handler,
+            retry_if: self.retry_if,
+            max_retries: self.max_retries,
+            retry_backoff: self.retry_backoff,
+            sleeper: self.sleeper,
+            metrics: self.metrics,
+            interceptors: self.interceptors.into(),
+            #[cfg(feature = "chrono")]
+            clock: self.clock,
+            #[cfg(feature = "chrono")]
+            auto_pace: self.auto_pace,
+            #[cfg(feature = "uuid")]
+            request_id_header,
+            #[cfg(feature = "uuid")]
+            last_request_id: Arc::new(std::sync::Mutex::new(None)),
+            #[cfg(feature = "cancellation")]
+            cancellation_token: self.cancellation_token,
+        })
+    }
+}
+
+/// Parses a base URL, which must be `http` or `https` without a query or
+/// fragment, adding a trailing slash so endpoints are joined under its path.
+fn parse_base_url(base_url: &str) -> Result<Url, Error> {
+    let mut url = Url::parse(base_url).map_err(|_| Error::InvalidBaseUrl)?;
+    if !matches!(url.scheme(), "http" | "https")
+        || url.query().is_some()
+        || url.fragment().is_some()
+    {
+        return Err(Error::InvalidBaseUrl);
+    }
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    Ok(url)
+}
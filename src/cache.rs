@@ -0,0 +1,123 @@
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    cache_store::{CacheStore, CachedEntry, MemoryCacheStore},
+    model::GetEventInfoRequest,
+};
+
+/// The get_event_info cache: responses kept in a [`CacheStore`] for `ttl`.
+///
+/// Expiry is always checked here, against the client's clock, so stores
+/// never need a clock of their own.
+#[derive(Debug, Clone)]
+pub(crate) struct EventInfoCache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+    /// The bound of the default in-memory store, unknown for custom ones.
+    max_entries: Option<usize>,
+}
+
+impl EventInfoCache {
+    /// A cache in memory, keeping at most `max_entries` responses.
+    pub fn in_memory(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            store: Arc::new(MemoryCacheStore::with_max_entries(max_entries)),
+            ttl,
+            max_entries: Some(max_entries),
+        }
+    }
+
+    /// A cache in `store`.
+    pub fn in_store(store: Arc<dyn CacheStore>, ttl: Duration) -> Self {
+        Self {
+            store,
+            ttl,
+            max_entries: None,
+        }
+    }
+
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    pub fn max_entries(&self) -> Option<usize> {
+        self.max_entries
+    }
+
+    /// The cached response body, if present and not expired at `now`.
+    pub async fn get(&self, key: &str, now: SystemTime) -> Option<String> {
+        let entry = self.store.get(key).await?;
+        (entry.expires_at > now).then_some(entry.body)
+    }
+
+    /// Caches a response body, fresh for `ttl` from `now`.
+    pub async fn insert(&self, key: &str, body: String, now: SystemTime) {
+        let entry = CachedEntry {
+            body,
+            expires_at: now + self.ttl,
+        };
+        self.store.put(key, entry, self.ttl).await;
+    }
+
+    /// Removes every cached response for the Event, regardless of range.
+    pub async fn invalidate(&self, id: &str) {
+        self.store
+            .remove_matching(&|key| GetEventInfoRequest::is_cache_key_of(key, id))
+            .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    fn key(id: &str, start: Option<i32>) -> String {
+        GetEventInfoRequest {
+            id: id.into(),
+            start,
+            ..Default::default()
+        }
+        .cache_key()
+    }
+
+    #[tokio::test]
+    async fn expires_entries_after_ttl() {
+        let cache = EventInfoCache::in_memory(Duration::from_secs(60), 2);
+        cache.insert(&key("a", None), "{}".into(), at(100)).await;
+
+        // A clock set backwards keeps entries fresh rather than expiring them.
+        assert!(cache.get(&key("a", None), at(50)).await.is_some());
+        assert!(cache.get(&key("a", None), at(159)).await.is_some());
+        assert!(cache.get(&key("a", None), at(160)).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn stores_nothing_with_zero_max_entries() {
+        let cache = EventInfoCache::in_memory(Duration::from_secs(60), 0);
+        cache.insert(&key("a", None), "{}".into(), at(0)).await;
+
+        assert!(cache.get(&key("a", None), at(0)).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn invalidates_all_ranges_of_an_event() {
+        let cache = EventInfoCache::in_memory(Duration::from_secs(60), 10);
+        cache.insert(&key("a", None), "{}".into(), at(0)).await;
+        cache
+            .insert(&key("a", Some(2020)), "{}".into(), at(0))
+            .await;
+        cache.insert(&key("ab", None), "{}".into(), at(0)).await;
+        cache.invalidate("a").await;
+
+        assert!(cache.get(&key("a", None), at(0)).await.is_none());
+        assert!(cache.get(&key("a", Some(2020)), at(0)).await.is_none());
+        assert!(cache.get(&key("ab", None), at(0)).await.is_some());
+    }
+}
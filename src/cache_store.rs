@@ -0,0 +1,229 @@
+//! Pluggable storage for cached responses, e.g. to keep them across restarts.
+
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "disk-cache")]
+mod dir;
+
+#[cfg(feature = "disk-cache")]
+pub use dir::DirCacheStore;
+
+/// A cached response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedEntry {
+    /// The response's JSON body
+    pub body: String,
+    /// When the entry expires, according to the client's clock
+    pub expires_at: SystemTime,
+}
+
+/// Storage for the get_event_info cache, set with
+/// [`ClientBuilder::cache_store`](crate::ClientBuilder::cache_store). The
+/// default is a [`MemoryCacheStore`], see
+/// [`ClientBuilder::event_info_cache`](crate::ClientBuilder::event_info_cache).
+///
+/// Keys are the requests' `cache_key`s, e.g.
+/// [`GetEventInfoRequest::cache_key`](crate::model::GetEventInfoRequest::cache_key).
+/// Implement it to share cached responses across processes, e.g. in Redis.
+///
+/// The client checks `expires_at` against its own clock, so stores may
+/// return expired entries, and shouldn't compare `expires_at` with a clock of
+/// their own. Storing is best-effort: a store that fails to read or write an
+/// entry should behave as if it wasn't cached, rather than fail the request.
+/// Stores may drop entries whenever they like, e.g. once `ttl` has passed.
+pub trait CacheStore: Debug + Send + Sync {
+    /// The entry stored under `key`, if any.
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<CachedEntry>>;
+
+    /// Stores `entry` under `key`, replacing any previous one. `ttl` is how
+    /// long it's fresh for, up to `entry.expires_at`.
+    fn put<'a>(&'a self, key: &'a str, entry: CachedEntry, ttl: Duration) -> BoxFuture<'a, ()>;
+
+    /// Removes the entries whose keys `matches`, e.g. every range of an Event
+    /// for [`HolidayEventApi::invalidate_event`](crate::HolidayEventApi::invalidate_event).
+    ///
+    /// Does nothing by default, so entries stay until they expire.
+    fn remove_matching<'a>(
+        &'a self,
+        matches: &'a (dyn Fn(&str) -> bool + Send + Sync),
+    ) -> BoxFuture<'a, ()> {
+        let _ = matches;
+        Box::pin(async {})
+    }
+}
+
+/// A [`CacheStore`] in memory, so entries are lost when it's dropped.
+///
+/// Storing an entry drops those that expired before it was stored, i.e.
+/// before its `expires_at - ttl` by the client's clock. Past `max_entries`,
+/// the entries expiring soonest are dropped too.
+#[derive(Debug)]
+pub struct MemoryCacheStore {
+    max_entries: usize,
+    entries: Mutex<HashMap<String, CachedEntry>>,
+}
+
+impl Default for MemoryCacheStore {
+    fn default() -> Self {
+        Self::with_max_entries(usize::MAX)
+    }
+}
+
+impl MemoryCacheStore {
+    /// An empty store, without a bound on its number of entries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An empty store keeping at most `max_entries` entries. With 0, it
+    /// stores nothing.
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl CacheStore for MemoryCacheStore {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<CachedEntry>> {
+        let entry = self.entries.lock().unwrap().get(key).cloned();
+        Box::pin(async move { entry })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, entry: CachedEntry, ttl: Duration) -> BoxFuture<'a, ()> {
+        if self.max_entries > 0 {
+            let mut entries = self.entries.lock().unwrap();
+            if let Some(now) = entry.expires_at.checked_sub(ttl) {
+                entries.retain(|_, entry| entry.expires_at > now);
+            }
+            while !entries.contains_key(key) && entries.len() >= self.max_entries {
+                let soonest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.expires_at)
+                    .map(|(key, _)| key.clone())
+                    .unwrap();
+                entries.remove(&soonest);
+            }
+            entries.insert(key.into(), entry);
+        }
+        Box::pin(async {})
+    }
+
+    fn remove_matching<'a>(
+        &'a self,
+        matches: &'a (dyn Fn(&str) -> bool + Send + Sync),
+    ) -> BoxFuture<'a, ()> {
+        self.entries.lock().unwrap().retain(|key, _| !matches(key));
+        Box::pin(async {})
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn entry(body: &str, ttl: Duration) -> CachedEntry {
+        CachedEntry {
+            body: body.into(),
+            expires_at: SystemTime::now() + ttl,
+        }
+    }
+
+    /// Checks the behavior every store must have.
+    pub(crate) async fn check_contract(store: &dyn CacheStore) {
+        let ttl = Duration::from_secs(60);
+        assert_eq!(None, store.get("event|id=a").await);
+
+        let a = entry("{\"a\":1}", ttl);
+        store.put("event|id=a", a.clone(), ttl).await;
+        assert_eq!(Some(&a), store.get("event|id=a").await.as_ref());
+        assert_eq!(None, store.get("event|id=b").await);
+
+        let replaced = entry("{\"a\":2}", ttl);
+        store.put("event|id=a", replaced.clone(), ttl).await;
+        assert_eq!(Some(&replaced), store.get("event|id=a").await.as_ref());
+
+        // Keys may contain any characters.
+        let odd = "search|adult=false|query=a/b\\c:d?*\"<>|é";
+        store.put(odd, a.clone(), ttl).await;
+        assert_eq!(Some(a), store.get(odd).await);
+
+        // Removing is optional, but the crate's stores support it.
+        store.remove_matching(&|key| key == odd).await;
+        assert_eq!(None, store.get(odd).await);
+        assert_eq!(Some(replaced), store.get("event|id=a").await);
+    }
+
+    #[tokio::test]
+    async fn memory_store_meets_contract() {
+        check_contract(&MemoryCacheStore::new()).await;
+    }
+
+    fn entry_at(body: &str, now: u64, ttl: Duration) -> CachedEntry {
+        CachedEntry {
+            body: body.into(),
+            expires_at: SystemTime::UNIX_EPOCH + Duration::from_secs(now) + ttl,
+        }
+    }
+
+    #[tokio::test]
+    async fn memory_store_drops_expired_entries_by_the_clients_clock() {
+        let store = MemoryCacheStore::new();
+        let ttl = Duration::from_secs(60);
+        store.put("old", entry_at("{}", 0, ttl), ttl).await;
+        store.put("recent", entry_at("{}", 30, ttl), ttl).await;
+        // Long before the system clock's now.
+        store.put("new", entry_at("{}", 60, ttl), ttl).await;
+
+        assert_eq!(None, store.get("old").await);
+        assert!(store.get("recent").await.is_some());
+        assert!(store.get("new").await.is_some());
+    }
+
+    #[tokio::test]
+    async fn memory_store_evicts_entries_expiring_soonest_when_full() {
+        let store = MemoryCacheStore::with_max_entries(2);
+        let ttl = Duration::from_secs(60);
+        store.put("a", entry_at("{}", 0, ttl), ttl).await;
+        store.put("b", entry_at("{}", 1, ttl), ttl).await;
+        // Replacing an entry doesn't evict another.
+        store.put("a", entry_at("{}", 2, ttl), ttl).await;
+        assert!(store.get("b").await.is_some());
+
+        store.put("c", entry_at("{}", 3, ttl), ttl).await;
+        assert_eq!(None, store.get("b").await);
+        assert!(store.get("a").await.is_some());
+        assert!(store.get("c").await.is_some());
+    }
+
+    #[tokio::test]
+    async fn memory_store_stores_nothing_with_zero_max_entries() {
+        let store = MemoryCacheStore::with_max_entries(0);
+        let ttl = Duration::from_secs(60);
+        store.put("a", entry("{}", ttl), ttl).await;
+        assert_eq!(None, store.get("a").await);
+    }
+
+    #[tokio::test]
+    async fn memory_store_removes_matching_entries() {
+        let store = MemoryCacheStore::new();
+        let ttl = Duration::from_secs(60);
+        store.put("a1", entry("{}", ttl), ttl).await;
+        store.put("a2", entry("{}", ttl), ttl).await;
+        store.put("b", entry("{}", ttl), ttl).await;
+        store.remove_matching(&|key| key.starts_with('a')).await;
+
+        assert_eq!(None, store.get("a1").await);
+        assert_eq!(None, store.get("a2").await);
+        assert!(store.get("b").await.is_some());
+    }
+}
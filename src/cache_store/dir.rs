@@ -0,0 +1,227 @@
+use std::{
+    fmt::Write,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use futures_util::future::BoxFuture;
+
+use super::{CacheStore, CachedEntry};
+
+/// A [`CacheStore`] in a directory, with one JSON file per entry, so entries
+/// survive restarts.
+///
+/// Entries are written to a temporary file and then renamed into place, so
+/// concurrent readers, even in other processes, never see a partial entry.
+/// Files that can't be read or parsed count as missing. Expired files are
+/// left in place until replaced or removed, since a reader can't tell whether
+/// another writer just renamed a fresh one in. File I/O is blocking, but the
+/// files are small.
+#[derive(Debug, Clone)]
+pub struct DirCacheStore {
+    dir: PathBuf,
+}
+
+impl DirCacheStore {
+    /// A store in `dir`, which is created if missing.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    /// The directory entries are stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The file of `key`: its hex-encoded bytes, so any key is a valid file
+    /// name.
+    fn path(&self, key: &str) -> PathBuf {
+        let mut name = String::with_capacity(key.len() * 2 + 5);
+        for byte in key.bytes() {
+            write!(name, "{:02x}", byte).unwrap();
+        }
+        name.push_str(".json");
+        self.dir.join(name)
+    }
+
+    /// The key of the file `name`, unless it isn't an entry's, e.g. a
+    /// temporary file.
+    fn key(name: &str) -> Option<String> {
+        let hex = name.strip_suffix(".json")?;
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+            .collect::<Option<Vec<_>>>()?;
+        String::from_utf8(bytes).ok()
+    }
+
+    fn read(&self, key: &str) -> Option<CachedEntry> {
+        serde_json::from_slice(&fs::read(self.path(key)).ok()?).ok()
+    }
+
+    fn remove(&self, matches: &dyn Fn(&str) -> bool) -> io::Result<()> {
+        for file in fs::read_dir(&self.dir)? {
+            let file = file?;
+            let key = file.file_name().to_str().and_then(Self::key);
+            if key.is_some_and(|key| matches(&key)) {
+                let _ = fs::remove_file(file.path());
+            }
+        }
+        Ok(())
+    }
+
+    fn write(&self, key: &str, entry: &CachedEntry) -> io::Result<()> {
+        static WRITES: AtomicU64 = AtomicU64::new(0);
+
+        let path = self.path(key);
+        let temp = path.with_extension(format!(
+            "{}-{}.tmp",
+            std::process::id(),
+            WRITES.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&temp, serde_json::to_vec(entry)?)?;
+        fs::rename(&temp, &path).inspect_err(|_| {
+            let _ = fs::remove_file(&temp);
+        })
+    }
+}
+
+impl CacheStore for DirCacheStore {
+    fn get<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Option<CachedEntry>> {
+        Box::pin(async move { self.read(key) })
+    }
+
+    fn put<'a>(&'a self, key: &'a str, entry: CachedEntry, _ttl: Duration) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let _ = self.write(key, &entry);
+        })
+    }
+
+    fn remove_matching<'a>(
+        &'a self,
+        matches: &'a (dyn Fn(&str) -> bool + Send + Sync),
+    ) -> BoxFuture<'a, ()> {
+        Box::pin(async move {
+            let _ = self.remove(matches);
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::cache_store::tests::{check_contract, entry};
+
+    /// A new, empty directory for a test.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("holiday_event_api-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[tokio::test]
+    async fn meets_contract() {
+        let dir = temp_dir("contract");
+        check_contract(&DirCacheStore::new(&dir).unwrap()).await;
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn survives_restarts() {
+        let dir = temp_dir("restart");
+        let ttl = Duration::from_secs(60);
+        let cached = entry("{}", ttl);
+        DirCacheStore::new(&dir)
+            .unwrap()
+            .put("key", cached.clone(), ttl)
+            .await;
+        assert_eq!(
+            Some(cached),
+            DirCacheStore::new(&dir).unwrap().get("key").await
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn keeps_expired_entries_for_the_client_to_check() {
+        let dir = temp_dir("expiry");
+        let store = DirCacheStore::new(&dir).unwrap();
+        let expired = entry("{}", Duration::ZERO);
+        store.put("key", expired.clone(), Duration::ZERO).await;
+
+        assert_eq!(Some(expired), store.get("key").await);
+        assert!(store.path("key").exists());
+
+        // Replaced once refetched.
+        let ttl = Duration::from_secs(60);
+        let fresh = entry("{\"a\":1}", ttl);
+        store.put("key", fresh.clone(), ttl).await;
+        assert_eq!(Some(fresh), store.get("key").await);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn removes_matching_entries_only() {
+        let dir = temp_dir("remove");
+        let store = DirCacheStore::new(&dir).unwrap();
+        let ttl = Duration::from_secs(60);
+        store.put("event|id=a", entry("{}", ttl), ttl).await;
+        store.put("event|id=b", entry("{}", ttl), ttl).await;
+        fs::write(dir.join("unrelated.txt"), "").unwrap();
+        store.remove_matching(&|key| key == "event|id=a").await;
+
+        assert_eq!(None, store.get("event|id=a").await);
+        assert!(store.get("event|id=b").await.is_some());
+        assert!(dir.join("unrelated.txt").exists());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn treats_corrupt_files_as_missing() {
+        let dir = temp_dir("corrupt");
+        let store = DirCacheStore::new(&dir).unwrap();
+        fs::write(store.path("key"), "{\"body\":").unwrap();
+        assert_eq!(None, store.get("key").await);
+
+        let ttl = Duration::from_secs(60);
+        let cached = entry("{}", ttl);
+        store.put("key", cached.clone(), ttl).await;
+        assert_eq!(Some(cached), store.get("key").await);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn handles_concurrent_access() {
+        let dir = temp_dir("concurrent");
+        let store = Arc::new(DirCacheStore::new(&dir).unwrap());
+        let ttl = Duration::from_secs(60);
+        let tasks: Vec<_> = (0..16)
+            .map(|i| {
+                let store = store.clone();
+                tokio::spawn(async move {
+                    let body = format!("{{\"writer\":{}}}", i);
+                    for _ in 0..20 {
+                        store.put("shared", entry(&body, ttl), ttl).await;
+                        // Every read sees a whole entry from some writer.
+                        let read = store.get("shared").await.unwrap();
+                        assert!(read.body.starts_with("{\"writer\":"), "{}", read.body);
+                    }
+                })
+            })
+            .collect();
+        for task in tasks {
+            task.await.unwrap();
+        }
+
+        let files: Vec<_> = fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(1, files.len());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
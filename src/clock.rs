@@ -0,0 +1,24 @@
+use std::fmt::Debug;
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time.
+///
+/// Every time-dependent feature of the client (e.g. cache expiry and relative
+/// year ranges) reads the time from its clock, set with
+/// [`ClientBuilder::with_clock`](crate::ClientBuilder::with_clock). Defaults
+/// to [`SystemClock`].
+pub trait Clock: Debug + Send + Sync {
+    /// The current time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The system's clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,116 @@
+use std::{fmt, time::Duration};
+
+use reqwest::Url;
+
+use crate::{AuthScheme, HolidayEventApi};
+
+/// A snapshot of a client's configuration, returned by
+/// [`HolidayEventApi::config`], e.g. for startup logs.
+///
+/// It never contains API keys, and passwords in URLs are replaced with
+/// `redacted`. Its
+/// `Display` is a single line of `key=value` pairs.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct ClientConfig {
+    /// The URL requests are sent to
+    pub base_url: Url,
+    /// The URL requests are retried against when they can't connect
+    pub fallback_base_url: Option<Url>,
+    /// How the API key is sent
+    pub auth_scheme: AuthScheme,
+    /// Whether an API key is configured
+    pub api_key_set: bool,
+    /// How many API keys are configured, see
+    /// [`ClientBuilder::api_keys`](crate::ClientBuilder::api_keys)
+    pub api_key_count: usize,
+    /// How long a request may take
+    pub timeout: Duration,
+    /// The `(max_retries, retry_backoff)` of failed requests, if
+    /// [`ClientBuilder::retry_if`](crate::ClientBuilder::retry_if) is set
+    pub retry: Option<(u32, Duration)>,
+    /// The timezone get_events uses when the request doesn't set one
+    pub default_timezone: Option<String>,
+    /// Whether get_events and search include adult Events when the request
+    /// doesn't say
+    pub default_adult: bool,
+    /// The get_event_info cache's `(ttl, max_entries)`, if enabled, where
+    /// `max_entries` is `None` for a
+    /// [`cache_store`](crate::ClientBuilder::cache_store)
+    pub event_info_cache: Option<(Duration, Option<usize>)>,
+    /// Whether the Rate Limit is read from responses
+    pub parse_rate_limit: bool,
+}
+
+impl fmt::Display for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "base_url={}", self.base_url)?;
+        if let Some(url) = &self.fallback_base_url {
+            write!(f, " fallback_base_url={}", url)?;
+        }
+        let auth = match &self.auth_scheme {
+            AuthScheme::ApiLayerHeader => "apikey-header".to_string(),
+            AuthScheme::BearerToken => "bearer".to_string(),
+            AuthScheme::QueryParam { name } => format!("query-param:{}", name),
+        };
+        write!(
+            f,
+            " auth={} api_key_set={} api_keys={} timeout={:?}",
+            auth, self.api_key_set, self.api_key_count, self.timeout
+        )?;
+        match self.retry {
+            Some((max_retries, backoff)) => {
+                write!(f, " retry=max:{},backoff:{:?}", max_retries, backoff)?
+            }
+            None => f.write_str(" retry=off")?,
+        }
+        if let Some(timezone) = &self.default_timezone {
+            write!(f, " default_timezone={}", timezone)?;
+        }
+        write!(f, " default_adult={}", self.default_adult)?;
+        match self.event_info_cache {
+            Some((ttl, Some(max_entries))) => write!(
+                f,
+                " event_info_cache=ttl:{:?},max_entries:{}",
+                ttl, max_entries
+            )?,
+            Some((ttl, None)) => write!(f, " event_info_cache=ttl:{:?},store:custom", ttl)?,
+            None => f.write_str(" event_info_cache=off")?,
+        }
+        write!(f, " parse_rate_limit={}", self.parse_rate_limit)
+    }
+}
+
+impl HolidayEventApi {
+    /// A snapshot of the client's configuration, without secrets.
+    pub fn config(&self) -> ClientConfig {
+        ClientConfig {
+            base_url: redact(&self.base_url),
+            fallback_base_url: self.fallback_base_url.as_ref().map(redact),
+            auth_scheme: self.auth_scheme.clone(),
+            api_key_set: !self.api_keys.is_empty(),
+            api_key_count: self.api_keys.len(),
+            timeout: self.timeout,
+            retry: self
+                .retry_if
+                .as_ref()
+                .map(|_| (self.max_retries, self.retry_backoff)),
+            default_timezone: self.default_timezone.clone(),
+            default_adult: self.default_adult,
+            event_info_cache: self
+                .event_info_cache
+                .as_ref()
+                .map(|cache| (cache.ttl(), cache.max_entries())),
+            parse_rate_limit: self.parse_rate_limit,
+        }
+    }
+}
+
+/// `url` without its password, if any.
+fn redact(url: &Url) -> Url {
+    let mut url = url.clone();
+    if url.password().is_some() {
+        let _ = url.set_password(Some("redacted"));
+    }
+    url
+}
@@ -0,0 +1,95 @@
+use chrono::{Datelike, Days, NaiveDate};
+
+use crate::{model, Error, HolidayEventApi};
+
+impl HolidayEventApi {
+    /// The number of days from `from` until the Event's next Occurrence, and
+    /// that Occurrence.
+    ///
+    /// An Occurrence that is ongoing on `from` (including multi-day Events
+    /// that started earlier) counts as 0 days away. Returns `None` if the
+    /// Event doesn't occur again within the next three years. Makes a single
+    /// get_event_info request.
+    pub async fn days_until_next(
+        &self,
+        id: &str,
+        from: NaiveDate,
+    ) -> Result<Option<(i64, model::Occurrence)>, Error> {
+        let response = self
+            .get_event_info(model::GetEventInfoRequest {
+                id: id.into(),
+                // Start a year early to find multi-day Occurrences that are still ongoing.
+                start: Some(from.year() - 1),
+                end: Some(from.year() + 3),
+                ..Default::default()
+            })
+            .await?;
+        Ok(next_occurrence(
+            response.event.occurrences.unwrap_or_default(),
+            from,
+        ))
+    }
+}
+
+fn next_occurrence(
+    occurrences: Vec<model::Occurrence>,
+    from: NaiveDate,
+) -> Option<(i64, model::Occurrence)> {
+    occurrences
+        .into_iter()
+        .filter_map(|occurrence| {
+            let start = occurrence.date.parse_flexible().date()?;
+            let end = start.checked_add_days(Days::new(occurrence.length.max(1) as u64))?;
+            if end <= from {
+                return None;
+            }
+            let days = (start - from).num_days().max(0);
+            Some((days, occurrence))
+        })
+        .min_by_key(|(days, _)| *days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{DateOrTimestamp, Occurrence};
+
+    fn occurrence(date: &str, length: i32) -> Occurrence {
+        Occurrence {
+            date: DateOrTimestamp::Date(date.into()),
+            length,
+        }
+    }
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn picks_the_soonest_occurrence_regardless_of_order() {
+        let occurrences = vec![
+            occurrence("08/08/2025", 1),
+            occurrence("08/08/2024", 1),
+            occurrence("08/08/2023", 1),
+        ];
+        assert_eq!(
+            Some((68, occurrence("08/08/2024", 1))),
+            next_occurrence(occurrences, ymd(2024, 6, 1))
+        );
+    }
+
+    #[test]
+    fn counts_occurrences_today_as_zero_days_away() {
+        let occurrences = vec![occurrence("08/08/2024", 1)];
+        assert_eq!(
+            Some((0, occurrence("08/08/2024", 1))),
+            next_occurrence(occurrences, ymd(2024, 8, 8))
+        );
+    }
+
+    #[test]
+    fn skips_unparseable_and_past_occurrences() {
+        let occurrences = vec![occurrence("someday", 1), occurrence("08/08/2024", 1)];
+        assert_eq!(None, next_occurrence(occurrences, ymd(2024, 8, 9)));
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+/// An API endpoint called by the client.
+///
+/// Its [`Display`](fmt::Display) form is a stable label, e.g. for metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Endpoint {
+    /// Called by get_events
+    Events,
+    /// Called by get_event_info
+    EventInfo,
+    /// Called by search
+    Search,
+}
+
+impl Endpoint {
+    /// The endpoint's path, relative to the base URL.
+    pub fn path(&self) -> &'static str {
+        match self {
+            Endpoint::Events => "events",
+            Endpoint::EventInfo => "event",
+            Endpoint::Search => "search",
+        }
+    }
+}
+
+impl fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.path())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_match_paths() {
+        for (endpoint, label) in [
+            (Endpoint::Events, "events"),
+            (Endpoint::EventInfo, "event"),
+            (Endpoint::Search, "search"),
+        ] {
+            assert_eq!(label, endpoint.path());
+            assert_eq!(label, endpoint.to_string());
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use std::fmt;
+
+use crate::Endpoint;
+
+/// An error returned by [`HolidayEventApi`](crate::HolidayEventApi).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The API key is missing or invalid
+    InvalidApiKey(String),
+    /// The underlying HTTP client could not be created
+    ClientBuild,
+    /// The base URL could not be parsed
+    InvalidBaseUrl,
+    /// The client needs to wait (e.g. to retry requests) but has no
+    /// [`Sleeper`](crate::Sleeper); set one with
+    /// [`ClientBuilder::sleeper`](crate::ClientBuilder::sleeper)
+    MissingSleeper,
+    /// An [`AuthScheme`](crate::AuthScheme) other than the default is set
+    /// without a base URL, see
+    /// [`ClientBuilder::auth_scheme`](crate::ClientBuilder::auth_scheme)
+    MissingBaseUrl,
+    /// A configured header name is invalid
+    InvalidHeaderName(String),
+    /// A configured header value is invalid
+    InvalidHeaderValue(String),
+    /// The Event id is missing
+    MissingId(String),
+    /// The search query is missing
+    MissingQuery(String),
+    /// The range of years is empty or out of bounds
+    InvalidRange {
+        /// The first year
+        start: i32,
+        /// The last year
+        end: i32,
+    },
+    /// A date or timestamp could not be parsed
+    InvalidDate(String),
+    /// A date format string is invalid
+    InvalidFormat(String),
+    /// A timezone is not a known IANA timezone
+    InvalidTimezone(String),
+    /// An extra query parameter would replace one set by this crate, without
+    /// `allow_override`
+    ReservedParam(String),
+    /// The request could not be sent or no response was received
+    Network(reqwest::Error),
+    /// The API responded with an error
+    Api {
+        /// The HTTP status code
+        status: u16,
+        /// The error message from the API, the start of a non-JSON body
+        /// (e.g. an HTML error page), or else the status' reason phrase
+        message: String,
+    },
+    /// The API rejected a parameter of the request
+    ///
+    /// Only known messages are recognized. Other rejections are
+    /// [`Error::Api`]. A search query that is too short is caught before
+    /// contacting the API, with [`Messages::short_query`].
+    Validation {
+        /// The parameter at fault
+        param: Param,
+        /// The error message from the API, or from [`Messages`]
+        message: String,
+    },
+    /// The response could not be parsed
+    Parse(serde_json::Error),
+    /// The response's body timed out after its headers were received, with
+    /// [`ClientBuilder::read_timeout`](crate::ClientBuilder::read_timeout)
+    /// set. Without it, such timeouts are [`Error::Network`].
+    BodyTimeout,
+    /// The API responded with a redirect while following redirects is disabled
+    Redirected {
+        /// The redirect's `Location` header, if any
+        location: Option<String>,
+        /// The HTTP status code
+        status: u16,
+    },
+    /// The API responded successfully, but with an empty or whitespace-only body
+    EmptyResponse {
+        /// The HTTP status code
+        status: u16,
+    },
+    /// A request would have been sent to a host other than the one allowed by
+    /// [`ClientBuilder::forbid_network_except`](crate::ClientBuilder::forbid_network_except)
+    NetworkForbidden(String),
+    /// The client was closed with [`HolidayEventApi::close`](crate::HolidayEventApi::close)
+    ClientClosed,
+    /// The request was cancelled with
+    /// [`ClientBuilder::cancellation_token`](crate::ClientBuilder::cancellation_token)
+    #[cfg(feature = "cancellation")]
+    Cancelled,
+    /// The monthly quota of every configured API key is exhausted
+    QuotaExhausted {
+        /// The error message from the API for the last key tried
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidApiKey(message) => f.write_str(message),
+            Error::ClientBuild => f.write_str("Error instantiating client."),
+            Error::InvalidBaseUrl => f.write_str("Invalid base_url."),
+            Error::MissingSleeper => f.write_str("No sleeper is configured."),
+            Error::MissingBaseUrl => f.write_str("A base_url is required with this auth scheme."),
+            Error::InvalidHeaderName(name) => write!(f, "Invalid header name: {}", name),
+            Error::InvalidHeaderValue(value) => write!(f, "Invalid header value: {:?}", value),
+            Error::MissingId(message) => f.write_str(message),
+            Error::MissingQuery(message) => f.write_str(message),
+            Error::InvalidRange { start, end } => {
+                write!(f, "Invalid occurrence range: {} to {}.", start, end)
+            }
+            Error::InvalidDate(date) => write!(f, "Invalid date: {}", date),
+            Error::InvalidFormat(format) => write!(f, "Invalid date format: {}", format),
+            Error::InvalidTimezone(tz) => write!(f, "Invalid timezone: {}", tz),
+            Error::ReservedParam(key) => write!(f, "Reserved query parameter: {}", key),
+            Error::Network(e) => write!(f, "Can't process request: {}", classify(e)),
+            Error::Api { message, .. } => f.write_str(message),
+            Error::Validation { message, .. } => f.write_str(message),
+            Error::Parse(e) => write!(f, "Can't parse response: {}", e),
+            Error::Redirected {
+                location: Some(location),
+                status,
+            } => write!(f, "Redirected ({}) to {}", status, location),
+            Error::Redirected {
+                location: None,
+                status,
+            } => write!(f, "Redirected ({})", status),
+            Error::BodyTimeout => f.write_str("Timed out reading the response."),
+            Error::EmptyResponse { status } => write!(f, "Empty response ({})", status),
+            Error::NetworkForbidden(host) => write!(f, "Requests to {} are forbidden.", host),
+            Error::ClientClosed => f.write_str("The client is closed."),
+            #[cfg(feature = "cancellation")]
+            Error::Cancelled => f.write_str("The request was cancelled."),
+            Error::QuotaExhausted { message } => f.write_str(message),
+        }
+    }
+}
+
+impl Error {
+    /// Whether the API rejected the API key.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(
+            self,
+            Error::Api {
+                status: 401 | 403,
+                ..
+            }
+        )
+    }
+
+    /// Whether the API rejected a search for matching too many Events.
+    ///
+    /// See [`HolidayEventApi::search_refined`](crate::HolidayEventApi::search_refined).
+    pub fn is_too_many_results(&self) -> bool {
+        matches!(self, Error::Validation { message, .. } if message.starts_with(TOO_MANY_RESULTS))
+    }
+}
+
+/// A request parameter, as reported by [`Error::Validation`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Param {
+    /// The search query
+    Query,
+    /// Whether to include adult Events
+    Adult,
+    /// The date to get Events for
+    Date,
+    /// The timezone
+    Timezone,
+    /// The Event id
+    Id,
+    /// The first year of Occurrences
+    Start,
+    /// The last year of Occurrences
+    End,
+}
+
+impl Param {
+    /// The parameter's name in the query string, e.g. `query`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Param::Query => "query",
+            Param::Adult => "adult",
+            Param::Date => "date",
+            Param::Timezone => "timezone",
+            Param::Id => "id",
+            Param::Start => "start",
+            Param::End => "end",
+        }
+    }
+}
+
+const TOO_MANY_RESULTS: &str = "Too many results";
+
+/// The start of known 400 messages, and the parameter each blames.
+const VALIDATION_MESSAGES: &[(Endpoint, &str, Param)] = &[
+    (
+        Endpoint::Search,
+        "Please enter a longer search term",
+        Param::Query,
+    ),
+    (Endpoint::Search, TOO_MANY_RESULTS, Param::Query),
+];
+
+/// The error for a response with `status` and `message`.
+pub(crate) fn api_error(endpoint: Endpoint, status: u16, message: String) -> Error {
+    let param = VALIDATION_MESSAGES
+        .iter()
+        .find(|(e, prefix, _)| *e == endpoint && message.starts_with(prefix))
+        .map(|(_, _, param)| *param);
+    match param {
+        Some(param) if status == 400 => Error::Validation { param, message },
+        _ => Error::Api { status, message },
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Network(e) => Some(e),
+            Error::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Why an API key is invalid, as reported by
+/// [`HolidayEventApi::validate_key`](crate::HolidayEventApi::validate_key).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyError {
+    /// The key is empty
+    Empty,
+    /// The key contains a character other than printable ASCII, e.g. a space
+    InvalidCharacters {
+        /// The byte index of the first invalid character
+        index: usize,
+    },
+    /// The key is longer than [`MAX_API_KEY_LEN`] bytes
+    TooLong {
+        /// The key's length in bytes
+        len: usize,
+    },
+}
+
+/// The longest API key accepted, in bytes.
+pub const MAX_API_KEY_LEN: usize = 256;
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::Empty => f.write_str("The API key is empty."),
+            KeyError::InvalidCharacters { index } => {
+                write!(f, "The API key has an invalid character at byte {}.", index)
+            }
+            KeyError::TooLong { len } => write!(
+                f,
+                "The API key is too long: {} bytes, at most {} allowed.",
+                len, MAX_API_KEY_LEN
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// The error's message, unchanged from when the client's methods returned
+/// `Result<_, String>`, for code still handling errors as `String`s, e.g.
+/// `Result<_, String>` functions using `?`.
+impl From<Error> for String {
+    fn from(error: Error) -> Self {
+        error.to_string()
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        Error::Network(error)
+    }
+}
+
+/// A short description of a transport failure that, unlike the error's own
+/// message, doesn't depend on the operating system.
+pub(crate) fn classify(error: &reqwest::Error) -> &'static str {
+    if error.is_timeout() {
+        return "timeout";
+    }
+    let mut source = std::error::Error::source(error);
+    while let Some(e) = source {
+        if let Some(io) = e.downcast_ref::<std::io::Error>() {
+            match io.kind() {
+                std::io::ErrorKind::ConnectionRefused => return "connection refused",
+                std::io::ErrorKind::ConnectionReset => return "connection reset",
+                std::io::ErrorKind::TimedOut => return "timeout",
+                _ => {}
+            }
+        }
+        let message = e.to_string().to_lowercase();
+        if message.starts_with("dns error") {
+            return "dns error";
+        }
+        if message.contains("tls") || message.contains("certificate") || message.contains("ssl") {
+            return "tls error";
+        }
+        source = e.source();
+    }
+    if error.is_connect() {
+        "connection error"
+    } else if error.is_body() || error.is_decode() {
+        "response body error"
+    } else if error.is_redirect() {
+        "redirect error"
+    } else {
+        "request error"
+    }
+}
+
+/// The messages of errors detected by this crate before contacting the API.
+///
+/// Override them with [`ClientBuilder::messages`](crate::ClientBuilder::messages)
+/// to localize them. Messages returned by the API are passed through as-is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Messages {
+    /// Used by [`Error::InvalidApiKey`]
+    pub invalid_api_key: String,
+    /// Used by [`Error::MissingId`]
+    pub missing_id: String,
+    /// Used by [`Error::MissingQuery`]
+    pub missing_query: String,
+    /// Used by [`Error::Validation`] for a search query shorter than
+    /// [`SearchRequest::MIN_QUERY_LEN`](crate::model::SearchRequest::MIN_QUERY_LEN)
+    pub short_query: String,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self {
+            invalid_api_key: "Please provide a valid API key. Get one at https://apilayer.com/marketplace/checkiday-api#pricing.".into(),
+            missing_id: "Event id is required.".into(),
+            missing_query: "Search query is required.".into(),
+            short_query: "Please enter a longer search term.".into(),
+        }
+    }
+}
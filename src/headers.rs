@@ -0,0 +1,100 @@
+//! Typed access to response headers.
+//!
+//! Header names are case-insensitive, and values may be padded with
+//! whitespace. Missing, empty, or malformed values are all `None`.
+
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::HeaderMap;
+
+/// The header's value, without surrounding whitespace.
+fn value<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    let value = headers.get(name)?.to_str().ok()?.trim();
+    (!value.is_empty()).then_some(value)
+}
+
+/// An integer header, e.g. `x-ratelimit-remaining-month`.
+pub(crate) fn parse_i32(headers: &HeaderMap, name: &str) -> Option<i32> {
+    value(headers, name)?.parse().ok()
+}
+
+/// A number of seconds, e.g. `Retry-After: 120`.
+pub(crate) fn parse_duration_secs(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    value(headers, name)?.parse().ok().map(Duration::from_secs)
+}
+
+/// An HTTP date, e.g. `Retry-After: Wed, 21 Oct 2015 07:28:00 GMT`.
+pub(crate) fn parse_http_date(headers: &HeaderMap, name: &str) -> Option<SystemTime> {
+    httpdate::parse_http_date(value(headers, name)?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+
+    use super::*;
+
+    fn headers(value: &'static str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("X-Test", HeaderValue::from_static(value));
+        headers
+    }
+
+    #[test]
+    fn ignores_name_case() {
+        let headers = headers("5");
+        assert_eq!(Some(5), parse_i32(&headers, "x-test"));
+        assert_eq!(Some(5), parse_i32(&headers, "X-TEST"));
+    }
+
+    #[test]
+    fn parses_i32() {
+        assert_eq!(Some(42), parse_i32(&headers("42"), "x-test"));
+        assert_eq!(Some(-3), parse_i32(&headers("-3"), "x-test"));
+        assert_eq!(Some(7), parse_i32(&headers("007"), "x-test"));
+        assert_eq!(Some(42), parse_i32(&headers(" 42\t"), "x-test"));
+        assert_eq!(Some(i32::MAX), parse_i32(&headers("2147483647"), "x-test"));
+
+        assert_eq!(None, parse_i32(&HeaderMap::new(), "x-test"));
+        assert_eq!(None, parse_i32(&headers(""), "x-test"));
+        assert_eq!(None, parse_i32(&headers("  "), "x-test"));
+        assert_eq!(None, parse_i32(&headers("abc"), "x-test"));
+        assert_eq!(None, parse_i32(&headers("4 2"), "x-test"));
+        assert_eq!(None, parse_i32(&headers("1.5"), "x-test"));
+        assert_eq!(None, parse_i32(&headers("2147483648"), "x-test"));
+    }
+
+    #[test]
+    fn parses_duration_secs() {
+        let secs = |value| parse_duration_secs(&headers(value), "x-test");
+        assert_eq!(Some(Duration::from_secs(120)), secs("120"));
+        assert_eq!(Some(Duration::ZERO), secs("0"));
+        assert_eq!(Some(Duration::from_secs(9)), secs("009"));
+        assert_eq!(Some(Duration::from_secs(9)), secs(" 9 "));
+        assert_eq!(
+            Some(Duration::from_secs(u64::MAX)),
+            secs("18446744073709551615")
+        );
+
+        assert_eq!(None, parse_duration_secs(&HeaderMap::new(), "x-test"));
+        assert_eq!(None, secs(""));
+        assert_eq!(None, secs("-1"));
+        assert_eq!(None, secs("1.5"));
+        assert_eq!(None, secs("soon"));
+        assert_eq!(None, secs("18446744073709551616"));
+    }
+
+    #[test]
+    fn parses_http_date() {
+        let date = |value| parse_http_date(&headers(value), "x-test");
+        let expected = SystemTime::UNIX_EPOCH + Duration::from_secs(1_445_412_480);
+        assert_eq!(Some(expected), date("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(Some(expected), date(" Wed, 21 Oct 2015 07:28:00 GMT "));
+
+        assert_eq!(None, parse_http_date(&HeaderMap::new(), "x-test"));
+        assert_eq!(None, date(""));
+        assert_eq!(None, date("120"));
+        assert_eq!(None, date("Wed, 32 Oct 2015 07:28:00 GMT"));
+        assert_eq!(None, date("2015-10-21T07:28:00Z"));
+    }
+}
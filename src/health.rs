@@ -0,0 +1,48 @@
+use std::time::{Duration, Instant};
+
+use crate::{model, params::QueryParams, Endpoint, Error, HolidayEventApi};
+
+impl HolidayEventApi {
+    /// Checks whether the API is reachable and the API key is valid.
+    ///
+    /// Makes a single get_events request (costing one request of quota) that
+    /// must complete within `timeout`.
+    pub async fn health_check(&self, timeout: Duration) -> model::HealthStatus {
+        match self.probe(Some(timeout)).await {
+            Ok(response) => model::HealthStatus::Healthy {
+                remaining_month: response.rate_limit.remaining_month,
+            },
+            Err(e) if e.is_unauthorized() => model::HealthStatus::Unauthorized,
+            Err(Error::Network(e)) if e.is_connect() || e.is_timeout() => {
+                model::HealthStatus::Unreachable
+            }
+            Err(e) => model::HealthStatus::Degraded(e.to_string()),
+        }
+    }
+
+    /// Checks that the API is reachable and the API key is valid, measuring
+    /// how long that takes, e.g. as a deploy gate.
+    ///
+    /// Makes the same request as [`health_check`](Self::health_check). Fails
+    /// if the request does, and a rejected API key can be told apart with
+    /// [`Error::is_unauthorized`]. Responses slower than `slow_after` are
+    /// reported as not ok.
+    pub async fn health_report(&self, slow_after: Duration) -> Result<model::HealthReport, Error> {
+        let started = Instant::now();
+        let response = self.probe(None).await?;
+        let latency = started.elapsed();
+        Ok(model::HealthReport {
+            ok: latency <= slow_after,
+            latency,
+            rate_limit: response.rate_limit,
+            endpoint: Endpoint::Events,
+        })
+    }
+
+    async fn probe(&self, timeout: Option<Duration>) -> Result<model::GetEventsResponse, Error> {
+        let mut params = QueryParams::new();
+        params.insert_unique("adult", "false");
+        self.request_with_timeout(Endpoint::Events, params, timeout, None)
+            .await
+    }
+}
@@ -0,0 +1,30 @@
+use std::fmt::Debug;
+
+use reqwest::RequestBuilder;
+
+/// Inspects or changes every request before it's sent, e.g. to add headers.
+///
+/// Add with [`ClientBuilder::interceptor`](crate::ClientBuilder::interceptor).
+/// Interceptors run in the order they were added, after the API key and the
+/// client's own headers are set, and again for each retry (e.g. with the next
+/// API key).
+pub trait RequestInterceptor: Debug + Send + Sync {
+    /// Returns the request to send instead of `request`.
+    fn intercept(&self, request: RequestBuilder) -> RequestBuilder;
+}
+
+/// Prints the method and path of every request to stderr.
+///
+/// The query string isn't printed, since it may contain the API key (see
+/// [`AuthScheme::QueryParam`](crate::AuthScheme::QueryParam)).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoggingInterceptor;
+
+impl RequestInterceptor for LoggingInterceptor {
+    fn intercept(&self, request: RequestBuilder) -> RequestBuilder {
+        if let Some(Ok(built)) = request.try_clone().map(RequestBuilder::build) {
+            eprintln!("{} {}", built.method(), built.url().path());
+        }
+        request
+    }
+}
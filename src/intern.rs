@@ -0,0 +1,127 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use crate::model::{EventSummary, Link};
+
+/// Deduplicates Event ids, so equal ids share one allocation.
+///
+/// Responses keep their `String` ids; convert the summaries you hold on to
+/// into [`InternedSummary`]s with [`summary`](Self::summary). Ids repeat
+/// across days (e.g. multi-day Events), timezones, and queries, so this saves
+/// memory when holding many of them. In `benches/interning.rs`, the summaries
+/// of a month of get_events responses fetched in 4 timezones take 10% less
+/// memory. Each interned id costs an `Arc` and a set entry, so the summaries
+/// of a single timezone, where most Events occur once, take 6% more.
+///
+/// Interned ids are kept for the interner's lifetime; there are only a few
+/// thousand Events, so this stays small.
+#[derive(Debug, Default)]
+pub struct Interner {
+    ids: Mutex<HashSet<Arc<str>>>,
+}
+
+impl Interner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The previously interned id equal to `id`, or else `id`, now interned.
+    pub fn intern(&self, id: &str) -> Arc<str> {
+        let mut ids = self.ids.lock().unwrap();
+        match ids.get(id) {
+            Some(interned) => interned.clone(),
+            None => {
+                let id: Arc<str> = id.into();
+                ids.insert(id.clone());
+                id
+            }
+        }
+    }
+
+    /// `summary`, with its id interned.
+    pub fn summary(&self, summary: EventSummary) -> InternedSummary {
+        InternedSummary {
+            id: self.intern(&summary.id),
+            name: summary.name,
+            url: summary.url,
+            score: summary.score,
+        }
+    }
+
+    /// The number of distinct ids interned so far.
+    pub fn len(&self) -> usize {
+        self.ids.lock().unwrap().len()
+    }
+
+    /// Whether no ids were interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An [`EventSummary`] whose id is shared with equal ids, made by
+/// [`Interner::summary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct InternedSummary {
+    /// The Event Id
+    pub id: Arc<str>,
+    /// The Event name
+    pub name: String,
+    /// The Event URL
+    pub url: Link,
+    /// How relevant the Event is to the search query, if reported. Higher is more relevant.
+    pub score: Option<f64>,
+}
+
+impl From<InternedSummary> for EventSummary {
+    fn from(summary: InternedSummary) -> Self {
+        EventSummary {
+            id: summary.id.to_string(),
+            name: summary.name,
+            url: summary.url,
+            score: summary.score,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(id: &str) -> EventSummary {
+        EventSummary {
+            id: id.into(),
+            name: "International Cat Day".into(),
+            url: "https://www.checkiday.com/f90b893ea04939d7456f30c54f68d7b4/international-cat-day"
+                .into(),
+            score: None,
+        }
+    }
+
+    #[test]
+    fn shares_equal_ids() {
+        let interner = Interner::new();
+        let a = interner.intern("f90b893ea04939d7456f30c54f68d7b4");
+        let b = interner.intern(&String::from("f90b893ea04939d7456f30c54f68d7b4"));
+        let c = interner.intern("b80630ae75c35f34c0526173dd999cfc");
+
+        assert!(Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!("f90b893ea04939d7456f30c54f68d7b4", &*b);
+        assert_eq!(2, interner.len());
+    }
+
+    #[test]
+    fn keeps_summaries_otherwise_identical() {
+        let interner = Interner::new();
+        let original = summary("f90b893ea04939d7456f30c54f68d7b4");
+        let a = interner.summary(original.clone());
+        let b = interner.summary(original.clone());
+
+        assert!(Arc::ptr_eq(&a.id, &b.id));
+        assert_eq!(original, EventSummary::from(a));
+    }
+}
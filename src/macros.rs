@@ -0,0 +1,100 @@
+/// A `MM/DD/YYYY` date string for the API, checked at compile time.
+///
+/// Invalid dates, such as `13/40/2020` or `02/29/2021`, fail to compile.
+///
+/// ```
+/// use holiday_event_api::{date, model::GetEventsRequest};
+///
+/// // Pass this to HolidayEventApi::get_events
+/// let request = GetEventsRequest {
+///     date: Some(date!("08/08/2020").into()),
+///     ..Default::default()
+/// };
+/// assert_eq!(Some("08/08/2020".into()), request.date);
+/// ```
+#[macro_export]
+macro_rules! date {
+    ($date:literal) => {{
+        const DATE: &str = $date;
+        const _: () = assert!(
+            $crate::__is_valid_date(DATE),
+            "invalid date, expected a valid MM/DD/YYYY date"
+        );
+        DATE
+    }};
+}
+
+/// Whether `date` is a valid `MM/DD/YYYY` date. Used by [`date!`].
+#[doc(hidden)]
+pub const fn __is_valid_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[2] != b'/' || bytes[5] != b'/' {
+        return false;
+    }
+    let (Some(month), Some(day), Some(year)) = (
+        number(bytes, 0, 2),
+        number(bytes, 3, 5),
+        number(bytes, 6, 10),
+    ) else {
+        return false;
+    };
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    day >= 1 && day <= days
+}
+
+/// The number written in `bytes[start..end]`, if it's all digits.
+const fn number(bytes: &[u8], start: usize, end: usize) -> Option<u32> {
+    let mut value = 0;
+    let mut i = start;
+    while i < end {
+        if !bytes[i].is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_to_the_date() {
+        assert_eq!("08/08/2020", date!("08/08/2020"));
+        assert_eq!("02/29/2024", date!("02/29/2024"));
+    }
+
+    #[test]
+    fn accepts_valid_dates() {
+        for date in ["01/01/2020", "12/31/1999", "02/29/2000", "04/30/2021"] {
+            assert!(__is_valid_date(date), "{date}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_dates() {
+        for date in [
+            "13/40/2020",
+            "00/10/2020",
+            "04/31/2020",
+            "02/29/2021",
+            "02/29/1900",
+            "01/00/2020",
+            "1/1/2020",
+            "2020-08-08",
+            "08/08/20x0",
+            "",
+        ] {
+            assert!(!__is_valid_date(date), "{date}");
+        }
+    }
+}
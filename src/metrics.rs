@@ -0,0 +1,25 @@
+use std::{fmt::Debug, time::Duration};
+
+use crate::Endpoint;
+
+/// Receives a [`RequestMetric`] for every request the client sends.
+///
+/// Set with [`ClientBuilder::metrics`](crate::ClientBuilder::metrics).
+pub trait Metrics: Debug + Send + Sync {
+    /// Records a finished request.
+    fn record(&self, metric: &RequestMetric);
+}
+
+/// Describes a finished request.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RequestMetric {
+    /// The called endpoint
+    pub endpoint: Endpoint,
+    /// The HTTP status code, or `None` if no response was received
+    pub status: Option<u16>,
+    /// How long the request took, including reading the response
+    pub elapsed: Duration,
+    /// Whether the request succeeded
+    pub success: bool,
+}
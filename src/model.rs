@@ -1,18 +1,123 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use serde::Deserialize;
 
+mod cache_key;
+#[cfg(feature = "chrono")]
+mod dates;
+#[cfg(test)]
+mod fixtures;
+#[cfg(feature = "ical")]
+mod ical;
+pub mod links;
+#[cfg(feature = "markdown")]
+mod markdown;
+pub mod merge;
+pub(crate) mod names;
+#[cfg(feature = "chrono")]
+mod pacing;
+#[cfg(feature = "chrono")]
+mod range;
+#[cfg(feature = "html-sanitize")]
+mod sanitize;
+#[cfg(feature = "chrono")]
+mod schedule;
+mod similar;
+#[cfg(feature = "chrono")]
+mod stats;
+#[cfg(feature = "cli")]
+mod table;
+#[cfg(feature = "chrono-tz")]
+mod timezone;
+#[cfg(feature = "typed-urls")]
+mod typed_url;
+mod validate;
+
+#[cfg(feature = "chrono")]
+pub use dates::ParsedDate;
+#[cfg(feature = "chrono")]
+pub use range::{OccurrenceRange, MAX_YEAR, MIN_YEAR};
+#[cfg(feature = "html-sanitize")]
+pub use sanitize::SanitizePolicy;
+#[cfg(feature = "chrono")]
+pub use schedule::Schedule;
+#[cfg(feature = "chrono")]
+pub use stats::OccurrenceStats;
+#[cfg(feature = "chrono-tz")]
+pub use timezone::Timezone;
+#[cfg(feature = "typed-urls")]
+pub use typed_url::TypedUrl;
+pub use validate::ValidationWarning;
+pub(crate) use validate::WarningHandler;
+
+/// A URL returned by the API: a [`TypedUrl`] with the `typed-urls` feature,
+/// otherwise a `String`. Either way, `as_str()` returns its text.
+#[cfg(feature = "typed-urls")]
+pub type Link = TypedUrl;
+/// A URL returned by the API: a `TypedUrl` with the `typed-urls` feature,
+/// otherwise a `String`. Either way, `as_str()` returns its text.
+#[cfg(not(feature = "typed-urls"))]
+pub type Link = String;
+
+/// A timezone for get_events: a `Timezone` enum of chrono-tz timezones
+/// with the `chrono-tz` feature, otherwise a `String`. Either way, it
+/// converts from `&str`, and `as_str()` returns its name.
+#[cfg(not(feature = "chrono-tz"))]
+pub type Timezone = String;
+
 /// The Request struct for calling get_events.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct GetEventsRequest {
-    /// Date to get the events for. Defaults to today.
+    /// Date to get the events for. Defaults to [`DEFAULT_DATE`](Self::DEFAULT_DATE).
     pub date: Option<String>,
-    /// Include events that may be unsafe for viewing at work or by children. Default is false.
+    /// Include events that may be unsafe for viewing at work or by children. Defaults to the
+    /// client's [`default_adult`](crate::ClientBuilder::default_adult), which is
+    /// [`DEFAULT_ADULT`](Self::DEFAULT_ADULT).
     pub adult: Option<bool>,
-    /// IANA Time Zone for calculating dates and times. Defaults to America/Chicago.
-    pub timezone: Option<String>,
+    /// IANA Time Zone for calculating dates and times. Defaults to the client's
+    /// [`default_timezone`](crate::ClientBuilder::default_timezone), or
+    /// [`DEFAULT_TIMEZONE`](Self::DEFAULT_TIMEZONE).
+    pub timezone: Option<Timezone>,
+    /// Additional query parameters not (yet) modeled by this crate. Parameters modeled by this
+    /// crate are rejected with [`Error::ReservedParam`](crate::Error::ReservedParam), unless
+    /// `allow_override` is set.
+    pub extra_params: HashMap<String, String>,
+    /// Lets `extra_params` replace the parameters modeled by this crate. `apikey` is always
+    /// rejected.
+    pub allow_override: bool,
+}
+
+impl GetEventsRequest {
+    /// The API's default date, today in the request's timezone
+    pub const DEFAULT_DATE: &'static str = "today";
+    /// The API's default for including adult Events
+    pub const DEFAULT_ADULT: bool = false;
+    /// The API's default timezone
+    pub const DEFAULT_TIMEZONE: &'static str = "America/Chicago";
+
+    /// The request with the API's defaults filled in.
+    ///
+    /// The client's [`default_adult`](crate::ClientBuilder::default_adult) and
+    /// [`default_timezone`](crate::ClientBuilder::default_timezone) take
+    /// precedence over these when set.
+    pub fn resolved(&self) -> Self {
+        Self {
+            date: Some(self.date.as_deref().unwrap_or(Self::DEFAULT_DATE).into()),
+            adult: Some(self.adult.unwrap_or(Self::DEFAULT_ADULT)),
+            timezone: Some(
+                self.timezone
+                    .clone()
+                    .unwrap_or_else(|| Self::DEFAULT_TIMEZONE.into()),
+            ),
+            extra_params: self.extra_params.clone(),
+            allow_override: self.allow_override,
+        }
+    }
 }
 
 /// The Response struct returned by get_events
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[must_use]
 pub struct GetEventsResponse {
     /// Whether Adult entries can be included
     pub adult: bool,
@@ -30,19 +135,142 @@ pub struct GetEventsResponse {
     pub rate_limit: RateLimit,
 }
 
+impl GetEventsResponse {
+    /// Compares all fields except `rate_limit`.
+    pub fn eq_ignoring_rate_limit(&self, other: &Self) -> bool {
+        self.adult == other.adult
+            && self.date == other.date
+            && self.timezone == other.timezone
+            && self.events == other.events
+            && self.multiday_starting == other.multiday_starting
+            && self.multiday_ongoing == other.multiday_ongoing
+    }
+
+    /// All Events, regardless of category, deduplicated by id.
+    ///
+    /// Events are in the order `events`, `multiday_starting`,
+    /// `multiday_ongoing`, keeping the first occurrence of each id.
+    pub fn unique_events(&self) -> Vec<&EventSummary> {
+        let mut seen = HashSet::new();
+        self.buckets()
+            .map(|(_, event)| event)
+            .filter(|event| seen.insert(event.id.as_str()))
+            .collect()
+    }
+
+    /// All Events, each with the bucket it was returned in.
+    ///
+    /// Buckets are iterated in [`EventBucket::ALL`] order, and each bucket's
+    /// Events in the order returned by the API. An Event listed in several
+    /// buckets is yielded once per bucket.
+    pub fn buckets(&self) -> impl Iterator<Item = (EventBucket, &EventSummary)> {
+        EventBucket::ALL.into_iter().flat_map(move |bucket| {
+            self.events_in(bucket)
+                .iter()
+                .map(move |event| (bucket, event))
+        })
+    }
+
+    /// Whether the Date has any Events, for branching on empty responses.
+    ///
+    /// An empty response is ambiguous: the Date may have no Events, or none
+    /// your request allows, e.g. only adult Events without `adult`. The API
+    /// doesn't tell these apart, so both are [`EventsOutcome::NoEvents`].
+    pub fn outcome(&self) -> EventsOutcome {
+        match self.unique_events().len() {
+            0 => EventsOutcome::NoEvents,
+            count => EventsOutcome::HasEvents(count),
+        }
+    }
+
+    /// The Events in the given bucket.
+    pub fn events_in(&self, bucket: EventBucket) -> &[EventSummary] {
+        match bucket {
+            EventBucket::SingleDay => &self.events,
+            EventBucket::MultidayStarting => &self.multiday_starting,
+            EventBucket::MultidayOngoing => &self.multiday_ongoing,
+        }
+    }
+}
+
+/// The categories of Events returned by get_events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventBucket {
+    /// Events observed on the Date only (`events`)
+    SingleDay,
+    /// Multi-day Events that start on the Date (`multiday_starting`)
+    MultidayStarting,
+    /// Multi-day Events that are continuing their observance on the Date (`multiday_ongoing`)
+    MultidayOngoing,
+}
+
+impl EventBucket {
+    /// All buckets, in the order the API returns them.
+    pub const ALL: [EventBucket; 3] = [
+        EventBucket::SingleDay,
+        EventBucket::MultidayStarting,
+        EventBucket::MultidayOngoing,
+    ];
+}
+
+/// Whether a get_events response has any Events, see
+/// [`GetEventsResponse::outcome`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventsOutcome {
+    /// The number of distinct Events, across all buckets
+    HasEvents(usize),
+    /// No Events were returned
+    NoEvents,
+}
+
 /// The Request struct for calling get_event_info.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct GetEventInfoRequest {
     /// The ID of the requested Event.
     pub id: String,
-    /// The starting range of returned occurrences. Optional, defaults to 2 years prior.
+    /// The starting range of returned occurrences. Optional, defaults to 2 years prior (see
+    /// [`DEFAULT_START_OFFSET_YEARS`](Self::DEFAULT_START_OFFSET_YEARS)).
     pub start: Option<i32>,
-    /// The ending range of returned occurrences. Optional, defaults to 3 years in the future.
+    /// The ending range of returned occurrences. Optional, defaults to 3 years in the future (see
+    /// [`DEFAULT_END_OFFSET_YEARS`](Self::DEFAULT_END_OFFSET_YEARS)).
     pub end: Option<i32>,
+    /// Additional query parameters not (yet) modeled by this crate. Parameters modeled by this
+    /// crate are rejected with [`Error::ReservedParam`](crate::Error::ReservedParam), unless
+    /// `allow_override` is set.
+    pub extra_params: HashMap<String, String>,
+    /// Lets `extra_params` replace the parameters modeled by this crate. `apikey` is always
+    /// rejected.
+    pub allow_override: bool,
+}
+
+impl GetEventInfoRequest {
+    /// The API's default `start`, relative to the current year
+    pub const DEFAULT_START_OFFSET_YEARS: i32 = -2;
+    /// The API's default `end`, relative to the current year
+    pub const DEFAULT_END_OFFSET_YEARS: i32 = 3;
+
+    /// The request with the API's defaults filled in, given the current year,
+    /// e.g. from the `Clock` passed to `ClientBuilder::with_clock`.
+    pub fn resolved(&self, current_year: i32) -> Self {
+        Self {
+            id: self.id.clone(),
+            start: Some(
+                self.start
+                    .unwrap_or(current_year + Self::DEFAULT_START_OFFSET_YEARS),
+            ),
+            end: Some(
+                self.end
+                    .unwrap_or(current_year + Self::DEFAULT_END_OFFSET_YEARS),
+            ),
+            extra_params: self.extra_params.clone(),
+            allow_override: self.allow_override,
+        }
+    }
 }
 
 /// The Response struct returned by get_event_info
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[must_use]
 pub struct GetEventInfoResponse {
     /// The Event Info
     pub event: EventInfo,
@@ -50,17 +278,55 @@ pub struct GetEventInfoResponse {
     pub rate_limit: RateLimit,
 }
 
+impl GetEventInfoResponse {
+    /// Compares all fields except `rate_limit`.
+    pub fn eq_ignoring_rate_limit(&self, other: &Self) -> bool {
+        self.event == other.event
+    }
+}
+
 /// The Request struct for calling search.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SearchRequest {
-    /// The search query. Must be at least 3 characters long.
+    /// The search query. Must be at least
+    /// [`MIN_QUERY_LEN`](Self::MIN_QUERY_LEN) characters long.
     pub query: String,
-    /// Include events that may be unsafe for viewing at work or by children. Default is false.
+    /// Include events that may be unsafe for viewing at work or by children. Defaults to the
+    /// client's [`default_adult`](crate::ClientBuilder::default_adult), which is
+    /// [`DEFAULT_ADULT`](Self::DEFAULT_ADULT).
     pub adult: Option<bool>,
+    /// Additional query parameters not (yet) modeled by this crate. Parameters modeled by this
+    /// crate are rejected with [`Error::ReservedParam`](crate::Error::ReservedParam), unless
+    /// `allow_override` is set.
+    pub extra_params: HashMap<String, String>,
+    /// Lets `extra_params` replace the parameters modeled by this crate. `apikey` is always
+    /// rejected.
+    pub allow_override: bool,
+}
+
+impl SearchRequest {
+    /// The API's default for including adult Events
+    pub const DEFAULT_ADULT: bool = false;
+    /// The shortest query the API accepts, in characters
+    pub const MIN_QUERY_LEN: usize = 3;
+
+    /// The request with the API's defaults filled in.
+    ///
+    /// The client's [`default_adult`](crate::ClientBuilder::default_adult)
+    /// takes precedence over these when set.
+    pub fn resolved(&self) -> Self {
+        Self {
+            query: self.query.clone(),
+            adult: Some(self.adult.unwrap_or(Self::DEFAULT_ADULT)),
+            extra_params: self.extra_params.clone(),
+            allow_override: self.allow_override,
+        }
+    }
 }
 
 /// The Response struct returned by get_events
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[must_use]
 pub struct SearchResponse {
     /// The search query
     pub query: String,
@@ -72,15 +338,178 @@ pub struct SearchResponse {
     pub rate_limit: RateLimit,
 }
 
+impl SearchResponse {
+    /// Compares all fields except `rate_limit`.
+    pub fn eq_ignoring_rate_limit(&self, other: &Self) -> bool {
+        self.query == other.query && self.adult == other.adult && self.events == other.events
+    }
+
+    /// The found Events, most relevant first.
+    ///
+    /// Events without a score come after scored ones. Events with equal (or
+    /// no) scores keep the order returned by the API.
+    pub fn sorted_by_relevance(&self) -> Vec<&EventSummary> {
+        let mut events: Vec<&EventSummary> = self.events.iter().collect();
+        events.sort_by(|a, b| match (a.score, b.score) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+        events
+    }
+
+    /// The found Events, grouped by the uppercased first character of their
+    /// name, e.g. for an alphabetical directory.
+    ///
+    /// Names not starting with a letter are grouped under `#`. With the
+    /// `unicode` feature, accents are ignored, so "Été" is grouped under `E`.
+    /// Within a group, Events keep their order.
+    pub fn group_by_initial(&self) -> BTreeMap<char, Vec<&EventSummary>> {
+        let mut groups: BTreeMap<char, Vec<&EventSummary>> = BTreeMap::new();
+        for event in &self.events {
+            let initial = names::fold(&event.name)
+                .chars()
+                .next()
+                .filter(|c| c.is_alphabetic())
+                .and_then(|c| c.to_uppercase().next())
+                .unwrap_or('#');
+            groups.entry(initial).or_default().push(event);
+        }
+        groups
+    }
+}
+
+/// The merged results of several searches, returned by search_many
+#[derive(Debug)]
+pub struct MergedSearch {
+    /// The found Events, deduplicated by id
+    pub events: Vec<MergedEvent>,
+    /// The queries that failed, with their errors
+    pub errors: Vec<(String, crate::Error)>,
+    /// The Rate Limit reported by the last successful search
+    pub rate_limit: RateLimit,
+}
+
+/// An Event found by search_many
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergedEvent {
+    /// The found Event
+    pub event: EventSummary,
+    /// The queries that found this Event
+    pub queries: Vec<String>,
+}
+
+/// When get_event_info_batch and get_events_range stop issuing requests.
+/// Unlimited by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchLimits {
+    /// Stop once this time has passed. A request in flight at the deadline
+    /// still completes.
+    pub deadline: Option<std::time::Instant>,
+    /// Stop once the remaining monthly quota is at or below this, keeping it
+    /// for other uses. Only applies once a response reported the Rate Limit.
+    pub quota_floor: Option<i32>,
+}
+
+/// How far a batch got, returned by get_event_info_batch and get_events_range
+#[derive(Debug)]
+pub struct BatchOutcome {
+    /// The number of requests completed
+    pub completed: usize,
+    /// The number of requests not issued. A failed request counts as neither
+    /// completed nor skipped.
+    pub skipped: usize,
+    /// Why the batch stopped early, if it did
+    pub stop_reason: Option<StopReason>,
+}
+
+/// Why a batch stopped early
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum StopReason {
+    /// [`BatchLimits::deadline`] passed
+    Deadline,
+    /// The remaining quota reached [`BatchLimits::quota_floor`]
+    QuotaFloor,
+    /// A request failed with this error
+    Failed(crate::Error),
+}
+
+/// A way for search_refined to narrow a query matching too many Events
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RefineStrategy {
+    /// Appends the current month's name (in UTC), e.g. `day` becomes `day October`
+    AppendMonth,
+    /// Searches the query followed by each kind of Event, i.e. `Day`, `Week`, and `Month`,
+    /// merging the Events found by all three. Each is narrower than the query alone.
+    AppendKinds,
+}
+
+/// The result of search_refined
+#[derive(Debug, Clone, PartialEq)]
+pub struct RefinedSearch {
+    /// The search's response. After [`RefineStrategy::AppendKinds`], it's the first kind's
+    /// response, with the Events of the other kinds appended.
+    pub response: SearchResponse,
+    /// How the query was narrowed, or `None` if the original query succeeded
+    pub refinement: Option<Refinement>,
+}
+
+/// How search_refined narrowed a query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Refinement {
+    /// The strategy that succeeded
+    pub strategy: RefineStrategy,
+    /// The narrowed query. For [`RefineStrategy::AppendKinds`], the queries joined with ` OR `.
+    pub query: String,
+}
+
+/// The result of a health_check
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthStatus {
+    /// The API is reachable and the API key is valid
+    Healthy {
+        /// The amount of requests remaining this month
+        remaining_month: i32,
+    },
+    /// The API key was rejected
+    Unauthorized,
+    /// The API could not be reached in time
+    Unreachable,
+    /// The API was reached but the request failed
+    Degraded(String),
+}
+
+/// The result of a health_report
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthReport {
+    /// Whether the API responded within the expected time
+    pub ok: bool,
+    /// How long the request took
+    pub latency: std::time::Duration,
+    /// The Rate Limit reported by the response
+    pub rate_limit: RateLimit,
+    /// The endpoint that was requested
+    pub endpoint: crate::Endpoint,
+}
+
 /// Information about an Event
-#[derive(Debug, Deserialize, PartialEq)]
+///
+/// Large optional sections are boxed to keep the struct small, since they are
+/// absent on some plans. Strings, including each Occurrence's date, are still
+/// allocated one by one: the response outlives the body it's decoded from, so
+/// it can't borrow from it, and `Box<str>` or `Arc<str>` would need as many
+/// allocations.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct EventInfo {
     /// The Event Id
     pub id: String,
     /// The Event name
     pub name: String,
     /// The Event URL
-    pub url: String,
+    pub url: Link,
     /// Whether this Event is unsafe for children or viewing at work
     pub adult: bool,
     /// The Event's Alternate Names
@@ -88,13 +517,13 @@ pub struct EventInfo {
     /// The Event's hashtags
     pub hashtags: Option<Vec<String>>,
     /// The Event's images
-    pub image: Option<ImageInfo>,
+    pub image: Option<Box<ImageInfo>>,
     /// The Event's sources
     pub sources: Option<Vec<String>>,
     /// The Event's description
-    pub description: Option<RichText>,
+    pub description: Option<Box<RichText>>,
     /// How to observe the Event
-    pub how_to_observe: Option<RichText>,
+    pub how_to_observe: Option<Box<RichText>>,
     /// Patterns defining when the Event is observed
     pub patterns: Option<Vec<Pattern>>,
     /// The Event Occurrences (when it occurs)
@@ -102,13 +531,62 @@ pub struct EventInfo {
     /// The Event's founders
     pub founders: Option<Vec<FounderInfo>>,
     // The Event's Analytics
-    pub analytics: Option<Analytics>,
+    pub analytics: Option<Box<Analytics>>,
     // The Event's Tags
     pub tags: Option<Vec<Tag>>,
 }
 
+impl EventInfo {
+    /// A key for sorting Events by popularity, most popular first, e.g. with
+    /// `sort_by_key`: the overall rank, then the social rank.
+    ///
+    /// Unknown ranks (missing, or 0) are `i64::MAX`, sorting after all known
+    /// ones.
+    pub fn popularity_key(&self) -> (i64, i64) {
+        let rank = |rank: Option<i64>| rank.filter(|&rank| rank > 0).unwrap_or(i64::MAX);
+        match &self.analytics {
+            Some(analytics) => (rank(analytics.overall_rank), rank(analytics.social_rank)),
+            None => (i64::MAX, i64::MAX),
+        }
+    }
+
+    /// Whether `summary` is of this Event, comparing ids like
+    /// [`EventSummary::same_event`].
+    pub fn matches_summary(&self, summary: &EventSummary) -> bool {
+        self.id == summary.id
+    }
+
+    /// The Event's summary, as returned by get_events and search.
+    pub fn to_summary(&self) -> EventSummary {
+        EventSummary {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            url: self.url.clone(),
+            score: None,
+        }
+    }
+
+    /// The host names of the Event's sources, without duplicates, in order of
+    /// first appearance. Sources that aren't valid URLs are skipped.
+    pub fn source_domains(&self) -> Vec<String> {
+        let mut domains: Vec<String> = vec![];
+        for source in self.sources.iter().flatten() {
+            let Some(host) = reqwest::Url::parse(source)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+            else {
+                continue;
+            };
+            if !domains.contains(&host) {
+                domains.push(host);
+            }
+        }
+        domains
+    }
+}
+
 /// Information about an Event's Pattern
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Pattern {
     /// The first year this event is observed (None implies none or unknown)
     pub first_year: Option<i32>,
@@ -120,12 +598,21 @@ pub struct Pattern {
     pub observed_html: String,
     /// A description of how this event is observed (formatted as Markdown)
     pub observed_markdown: String,
-    /// For how many days this event is celebrated
+    /// For how many days this event is celebrated (0 or less if it varies
+    /// or is unknown)
     pub length: i32,
 }
 
+impl Pattern {
+    /// For how many days this event is celebrated, or None if it varies or is
+    /// unknown.
+    pub fn observed_length(&self) -> Option<u32> {
+        u32::try_from(self.length).ok().filter(|&length| length > 0)
+    }
+}
+
 /// Information about an Event's Occurrence
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Occurrence {
     /// The date or timestamp the Event occurs
     pub date: DateOrTimestamp,
@@ -133,7 +620,12 @@ pub struct Occurrence {
     pub length: i32,
 }
 
-#[derive(Debug, PartialEq)]
+/// A date string or a timestamp.
+///
+/// Numbers that can't be a timestamp (too large, or fractional) keep their
+/// text as a `Date`. Enable the `arbitrary-precision` feature to keep that
+/// text exact instead of rounding it to an `f64`.
+#[derive(Debug, Clone, PartialEq)]
 pub enum DateOrTimestamp {
     Date(String),
     Timestamp(i64),
@@ -164,7 +656,28 @@ impl<'de> Deserialize<'de> for DateOrTimestamp {
             where
                 E: serde::de::Error,
             {
-                Ok(DateOrTimestamp::Timestamp(date as i64))
+                Ok(i64::try_from(date)
+                    .map(DateOrTimestamp::Timestamp)
+                    .unwrap_or_else(|_| DateOrTimestamp::Date(date.to_string())))
+            }
+
+            fn visit_f64<E>(self, date: f64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(DateOrTimestamp::Date(date.to_string()))
+            }
+
+            // With arbitrary precision, numbers that don't fit an i64 or u64 arrive as a map.
+            #[cfg(feature = "arbitrary-precision")]
+            fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let number = serde_json::Number::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(DateOrTimestamp::Date(number.to_string()))
             }
 
             fn visit_str<E>(self, date: &str) -> Result<Self::Value, E>
@@ -180,7 +693,7 @@ impl<'de> Deserialize<'de> for DateOrTimestamp {
 }
 
 /// Information about an Event's Alternate Name
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct AlternateName {
     /// An Event's Alternate Name
     pub name: String,
@@ -191,7 +704,7 @@ pub struct AlternateName {
 }
 
 /// Formatted Text
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct RichText {
     /// Formatted as plain text
     pub text: Option<String>,
@@ -202,60 +715,108 @@ pub struct RichText {
 }
 
 /// A summary of an Event
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct EventSummary {
     /// The Event Id
     pub id: String,
     /// The Event name
     pub name: String,
     /// The Event URL
-    pub url: String,
+    pub url: Link,
+    /// How relevant the Event is to the search query, if reported. Higher is more relevant.
+    #[serde(default)]
+    pub score: Option<f64>,
+}
+
+impl EventSummary {
+    /// Whether both are the same Event, e.g. from different responses.
+    ///
+    /// The id is an Event's identity: other fields, such as the URL or
+    /// search score, may differ between responses for the same Event.
+    pub fn same_event(&self, other: &EventSummary) -> bool {
+        self.id == other.id
+    }
 }
 
 /// Information about an Event image
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct ImageInfo {
     /// A small image
-    pub small: String,
+    pub small: Link,
     /// A medium image
-    pub medium: String,
+    pub medium: Link,
     /// A large image
-    pub large: String,
+    pub large: Link,
 }
 
 /// Information about an Event Founder
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct FounderInfo {
     /// The Founder's name
     pub name: String,
     /// A link to the Founder
-    pub url: Option<String>,
+    pub url: Option<Link>,
     /// The date the Event was founded
     pub date: Option<String>,
 }
 
 /// Analytics about an Event
-#[derive(Debug, Deserialize, PartialEq)]
+///
+/// Fields missing from the response are `None`, so that partial analytics
+/// don't fail the whole response.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Analytics {
     /// The Event's overall rank. #1 is the most popular.
-    pub overall_rank: i32,
+    pub overall_rank: Option<i64>,
     /// The Event's social rank. #1 is the most popular.
-    pub social_rank: i32,
+    pub social_rank: Option<i64>,
     /// The number of social shares involving this event.
-    pub social_shares: i32,
+    pub social_shares: Option<i64>,
     /// The Event's popularity, as stars from 0-5. i.e. "★★☆☆☆"
-    pub popularity: String,
+    pub popularity: Option<String>,
+}
+
+impl Analytics {
+    /// The overall rank as an `i32`, like before it was optional: 0 if
+    /// unknown, and saturated if too large.
+    pub fn overall_rank(&self) -> i32 {
+        saturate(self.overall_rank)
+    }
+
+    /// The social rank as an `i32`, like before it was optional: 0 if
+    /// unknown, and saturated if too large.
+    pub fn social_rank(&self) -> i32 {
+        saturate(self.social_rank)
+    }
+
+    /// The number of social shares as an `i32`, like before it was optional:
+    /// 0 if unknown, and saturated if too large.
+    pub fn social_shares(&self) -> i32 {
+        saturate(self.social_shares)
+    }
+
+    /// The popularity, like before it was optional: empty if unknown.
+    pub fn popularity(&self) -> &str {
+        self.popularity.as_deref().unwrap_or_default()
+    }
+}
+
+/// `value` clamped to an `i32`, or 0 if unknown.
+fn saturate(value: Option<i64>) -> i32 {
+    value.map_or(0, |value| {
+        value.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+    })
 }
 
 /// A Tag that categorizes an Event
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct Tag {
     /// The Tag's name
     pub name: String,
 }
 
 /// Your API plan's current Rate Limit and status. Upgrade to increase these limits.
-#[derive(Debug, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Default)]
 pub struct RateLimit {
     /// The amount of requests allowed this month
     pub limit_month: i32,
@@ -263,6 +824,23 @@ pub struct RateLimit {
     pub remaining_month: i32,
 }
 
+impl RateLimit {
+    /// The Rate Limit reported by a response's headers, if any.
+    ///
+    /// A missing or malformed header counts as 0, unless both are missing.
+    pub fn from_headers(headers: &reqwest::header::HeaderMap) -> Option<Self> {
+        let limit_month = crate::headers::parse_i32(headers, "x-ratelimit-limit-month");
+        let remaining_month = crate::headers::parse_i32(headers, "x-ratelimit-remaining-month");
+        if limit_month.is_none() && remaining_month.is_none() {
+            return None;
+        }
+        Some(Self {
+            limit_month: limit_month.unwrap_or(0),
+            remaining_month: remaining_month.unwrap_or(0),
+        })
+    }
+}
+
 pub trait RateLimited {
     fn set_rate_limit(&mut self, rate_limit: RateLimit);
 }
@@ -284,3 +862,639 @@ impl RateLimited for SearchResponse {
         self.rate_limit = rate_limit;
     }
 }
+
+/// A response of one of the endpoints.
+pub(crate) trait Response:
+    serde::de::DeserializeOwned + std::fmt::Debug + RateLimited
+{
+    /// The response's validation warnings.
+    fn warnings(&self) -> Vec<ValidationWarning>;
+}
+
+impl Response for GetEventsResponse {
+    fn warnings(&self) -> Vec<ValidationWarning> {
+        self.validate()
+    }
+}
+
+impl Response for GetEventInfoResponse {
+    fn warnings(&self) -> Vec<ValidationWarning> {
+        self.event.validate()
+    }
+}
+
+impl Response for SearchResponse {
+    fn warnings(&self) -> Vec<ValidationWarning> {
+        self.validate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events_fixture() -> GetEventsResponse {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    mod date_or_timestamp {
+        use super::*;
+
+        fn parse(json: &str) -> DateOrTimestamp {
+            serde_json::from_str(json).unwrap()
+        }
+
+        #[test]
+        fn parses_timestamps_and_dates() {
+            assert_eq!(DateOrTimestamp::Timestamp(-1), parse("-1"));
+            assert_eq!(
+                DateOrTimestamp::Timestamp(i64::MAX),
+                parse(&i64::MAX.to_string())
+            );
+            assert_eq!(
+                DateOrTimestamp::Date("08/08/2020".into()),
+                parse("\"08/08/2020\"")
+            );
+        }
+
+        #[test]
+        fn keeps_numbers_too_large_for_a_timestamp() {
+            assert_eq!(
+                DateOrTimestamp::Date(u64::MAX.to_string()),
+                parse(&u64::MAX.to_string())
+            );
+            assert_eq!(DateOrTimestamp::Date("1.5".into()), parse("1.5"));
+        }
+
+        #[test]
+        #[cfg(not(feature = "arbitrary-precision"))]
+        fn rounds_huge_numbers() {
+            assert_eq!(
+                DateOrTimestamp::Date("1000000000000000000000000000000".into()),
+                parse("1000000000000000000000000000001")
+            );
+        }
+
+        #[test]
+        #[cfg(feature = "arbitrary-precision")]
+        fn keeps_huge_numbers_exact() {
+            assert_eq!(
+                DateOrTimestamp::Date("1000000000000000000000000000001".into()),
+                parse("1000000000000000000000000000001")
+            );
+        }
+    }
+
+    mod sorted_by_relevance {
+        use super::*;
+
+        fn search(json: &str) -> SearchResponse {
+            serde_json::from_str(json).unwrap()
+        }
+
+        fn names(response: &SearchResponse) -> Vec<&str> {
+            response
+                .sorted_by_relevance()
+                .iter()
+                .map(|e| e.name.as_str())
+                .collect()
+        }
+
+        #[test]
+        fn sorts_by_descending_score() {
+            let response = search(
+                r#"{"query":"day","adult":false,"events":[
+                    {"id":"1","name":"A","url":"https://www.checkiday.com/1/a","score":0.5},
+                    {"id":"2","name":"B","url":"https://www.checkiday.com/2/b"},
+                    {"id":"3","name":"C","url":"https://www.checkiday.com/3/c","score":2.25},
+                    {"id":"4","name":"D","url":"https://www.checkiday.com/4/d","score":0.5}
+                ]}"#,
+            );
+            assert_eq!(Some(2.25), response.events[2].score);
+            assert_eq!(None, response.events[1].score);
+            assert_eq!(vec!["C", "A", "D", "B"], names(&response));
+        }
+
+        #[test]
+        fn keeps_server_order_without_scores() {
+            let json = std::fs::read_to_string("testdata/search-default.json").unwrap();
+            let response = search(&json);
+            let server_order: Vec<&str> = response.events.iter().map(|e| e.name.as_str()).collect();
+            assert_eq!(server_order, names(&response));
+        }
+    }
+
+    mod group_by_initial {
+        use super::*;
+
+        fn groups(response: &SearchResponse) -> Vec<(char, Vec<&str>)> {
+            response
+                .group_by_initial()
+                .into_iter()
+                .map(|(initial, events)| {
+                    (initial, events.iter().map(|e| e.name.as_str()).collect())
+                })
+                .collect()
+        }
+
+        #[test]
+        fn groups_search_results() {
+            let json = std::fs::read_to_string("testdata/search-default.json").unwrap();
+            let response: SearchResponse = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                vec![(
+                    'N',
+                    vec!["National Zucchini Bread Day", "National Zucchini Day"]
+                )],
+                groups(&response)
+            );
+        }
+
+        #[test]
+        fn uppercases_and_groups_non_letters() {
+            let response: SearchResponse = serde_json::from_str(
+                r##"{"query":"day","adult":false,"events":[
+                    {"id":"1","name":"pi Day","url":"https://www.checkiday.com/1/a"},
+                    {"id":"2","name":"4th of July","url":"https://www.checkiday.com/2/b"},
+                    {"id":"3","name":"Pizza Day","url":"https://www.checkiday.com/3/c"},
+                    {"id":"4","name":"été","url":"https://www.checkiday.com/4/d"},
+                    {"id":"5","name":"","url":"https://www.checkiday.com/5/e"},
+                    {"id":"6","name":"#hashtag Day","url":"https://www.checkiday.com/6/f"}
+                ]}"##,
+            )
+            .unwrap();
+            #[cfg(not(feature = "unicode"))]
+            let expected = vec![
+                ('#', vec!["4th of July", "", "#hashtag Day"]),
+                ('P', vec!["pi Day", "Pizza Day"]),
+                ('É', vec!["été"]),
+            ];
+            // Accents are ignored, like in EventSummary::matches_name.
+            #[cfg(feature = "unicode")]
+            let expected = vec![
+                ('#', vec!["4th of July", "", "#hashtag Day"]),
+                ('E', vec!["été"]),
+                ('P', vec!["pi Day", "Pizza Day"]),
+            ];
+            assert_eq!(expected, groups(&response));
+        }
+    }
+
+    mod to_summary {
+        use super::*;
+
+        #[test]
+        fn copies_summary_fields() {
+            let event = fixtures::event_info();
+
+            let summary = event.to_summary();
+            assert_eq!(event.id, summary.id);
+            assert_eq!(event.name, summary.name);
+            assert_eq!(event.url, summary.url);
+        }
+    }
+
+    mod popularity_key {
+        use super::*;
+
+        fn event(id: &str, ranks: Option<(i64, i64)>) -> EventInfo {
+            let mut event = fixtures::event_info();
+            event.id = id.into();
+            event.analytics = ranks.map(|(overall_rank, social_rank)| {
+                Box::new(Analytics {
+                    overall_rank: Some(overall_rank),
+                    social_rank: Some(social_rank),
+                    social_shares: None,
+                    popularity: None,
+                })
+            });
+            event
+        }
+
+        #[test]
+        fn sorts_most_popular_first() {
+            let mut events = [
+                event("missing", None),
+                event("unranked", Some((0, 0))),
+                event("second", Some((2, 7))),
+                event("first", Some((1, 9))),
+                event("tied", Some((2, 3))),
+            ];
+            events.sort_by_key(EventInfo::popularity_key);
+            let ids: Vec<&str> = events.iter().map(|event| &*event.id).collect();
+            assert_eq!(vec!["first", "tied", "second", "missing", "unranked"], ids);
+        }
+
+        #[test]
+        fn ranks_unknowns_last() {
+            assert_eq!((i64::MAX, i64::MAX), event("a", None).popularity_key());
+            assert_eq!((5, i64::MAX), event("a", Some((5, 0))).popularity_key());
+        }
+    }
+
+    mod same_event {
+        use super::*;
+
+        #[test]
+        fn compares_ids_only() {
+            let event = events_fixture().events[0].clone();
+            let moved = EventSummary {
+                url: serde_json::from_value(
+                    format!("https://example.com/{}/renamed", event.id).into(),
+                )
+                .unwrap(),
+                score: Some(1.5),
+                ..event.clone()
+            };
+            assert!(event.same_event(&moved));
+            assert!(moved.same_event(&event));
+
+            let other = events_fixture().events[1].clone();
+            assert!(!event.same_event(&other));
+        }
+
+        #[test]
+        fn matches_summaries_by_id() {
+            let event = fixtures::event_info();
+
+            let mut summary = event.to_summary();
+            assert!(event.matches_summary(&summary));
+            summary.name = "Cat Day".into();
+            assert!(event.matches_summary(&summary));
+            summary.id = events_fixture().events[0].id.clone();
+            assert!(!event.matches_summary(&summary));
+        }
+    }
+
+    mod resolved {
+        use super::*;
+
+        #[test]
+        fn fills_in_defaults() {
+            let events = GetEventsRequest::default().resolved();
+            assert_eq!(Some("today".into()), events.date);
+            assert_eq!(Some(false), events.adult);
+            assert_eq!(Some("America/Chicago".into()), events.timezone);
+
+            let event_info = GetEventInfoRequest::default().resolved(2025);
+            assert_eq!(Some(2023), event_info.start);
+            assert_eq!(Some(2028), event_info.end);
+
+            let search = SearchRequest::default().resolved();
+            assert_eq!(Some(false), search.adult);
+        }
+
+        #[test]
+        fn keeps_explicit_values() {
+            let extra_params = HashMap::from([("a".into(), "b".into())]);
+
+            let events = GetEventsRequest {
+                date: Some("08/08/2020".into()),
+                adult: Some(true),
+                timezone: Some("UTC".into()),
+                extra_params: extra_params.clone(),
+                allow_override: true,
+            }
+            .resolved();
+            assert_eq!(Some("08/08/2020".into()), events.date);
+            assert_eq!(Some(true), events.adult);
+            assert_eq!(Some("UTC".into()), events.timezone);
+            assert_eq!(extra_params, events.extra_params);
+            assert!(events.allow_override);
+
+            let event_info = GetEventInfoRequest {
+                id: "abc".into(),
+                start: Some(2000),
+                end: Some(2001),
+                extra_params: extra_params.clone(),
+                allow_override: true,
+            }
+            .resolved(2025);
+            assert_eq!("abc", event_info.id);
+            assert_eq!(Some(2000), event_info.start);
+            assert_eq!(Some(2001), event_info.end);
+            assert_eq!(extra_params, event_info.extra_params);
+            assert!(event_info.allow_override);
+
+            let search = SearchRequest {
+                query: "pizza".into(),
+                adult: Some(true),
+                extra_params: extra_params.clone(),
+                allow_override: true,
+            }
+            .resolved();
+            assert_eq!("pizza", search.query);
+            assert_eq!(Some(true), search.adult);
+            assert_eq!(extra_params, search.extra_params);
+            assert!(search.allow_override);
+        }
+    }
+
+    mod analytics {
+        use super::*;
+
+        #[test]
+        fn parses_partial_analytics() {
+            let json =
+                std::fs::read_to_string("testdata/getEventInfo-partial-analytics.json").unwrap();
+            let event = serde_json::from_str::<GetEventInfoResponse>(&json)
+                .unwrap()
+                .event;
+
+            assert_eq!(
+                Some(Box::new(Analytics {
+                    overall_rank: Some(12),
+                    social_rank: None,
+                    social_shares: Some(12_345_678_901),
+                    popularity: None,
+                })),
+                event.analytics
+            );
+
+            // The rest of the Event parses as usual.
+            let mut expected = fixtures::event_info();
+            expected.analytics = event.analytics.clone();
+            assert_eq!(expected, event);
+        }
+
+        #[test]
+        fn keeps_the_old_accessors() {
+            let analytics = Analytics {
+                overall_rank: Some(12),
+                social_rank: None,
+                social_shares: Some(12_345_678_901),
+                popularity: None,
+            };
+            assert_eq!(12, analytics.overall_rank());
+            assert_eq!(0, analytics.social_rank());
+            assert_eq!(i32::MAX, analytics.social_shares());
+            assert_eq!("", analytics.popularity());
+        }
+    }
+
+    mod observed_length {
+        use super::*;
+
+        fn pattern(length: i32) -> Pattern {
+            serde_json::from_value(serde_json::json!({
+                "first_year": 2002,
+                "last_year": null,
+                "observed": "annually",
+                "observed_html": "annually",
+                "observed_markdown": "annually",
+                "length": length,
+            }))
+            .unwrap()
+        }
+
+        #[test]
+        fn keeps_positive_lengths() {
+            assert_eq!(Some(1), pattern(1).observed_length());
+            assert_eq!(Some(7), pattern(7).observed_length());
+        }
+
+        #[test]
+        fn treats_non_positive_lengths_as_unknown() {
+            assert_eq!(None, pattern(0).observed_length());
+            assert_eq!(None, pattern(-1).observed_length());
+            assert_eq!(None, pattern(i32::MIN).observed_length());
+        }
+    }
+
+    mod source_domains {
+        use crate::model::fixtures::event_info;
+
+        #[test]
+        fn lists_source_hosts() {
+            assert_eq!(
+                vec!["www.source.com", "www.source.org"],
+                event_info().source_domains()
+            );
+        }
+
+        #[test]
+        fn deduplicates_and_skips_invalid_sources() {
+            let mut event = event_info();
+            event.sources = Some(vec![
+                "https://www.source.com/1".into(),
+                "not a url".into(),
+                "mailto:someone@source.net".into(),
+                "http://WWW.SOURCE.COM/2".into(),
+                "https://www.source.org/2".into(),
+            ]);
+            assert_eq!(
+                vec!["www.source.com", "www.source.org"],
+                event.source_domains()
+            );
+        }
+
+        #[test]
+        fn handles_missing_sources() {
+            let mut event = event_info();
+            event.sources = None;
+            assert!(event.source_domains().is_empty());
+        }
+    }
+
+    mod buckets {
+        use super::*;
+
+        #[test]
+        fn yields_each_bucket_in_order() {
+            let response = events_fixture();
+            let buckets: Vec<(EventBucket, &str)> = response
+                .buckets()
+                .map(|(bucket, e)| (bucket, e.name.as_str()))
+                .collect();
+            assert_eq!(
+                vec![
+                    (EventBucket::SingleDay, "Cinco de Mayo"),
+                    (EventBucket::SingleDay, "Great Lakes Awareness Day"),
+                    (EventBucket::MultidayStarting, "Teacher Appreciation Week"),
+                    (EventBucket::MultidayOngoing, "Be Kind to Animals Week"),
+                    (
+                        EventBucket::MultidayOngoing,
+                        "National Children's Mental Health Awareness Week"
+                    ),
+                ],
+                buckets
+            );
+        }
+
+        #[test]
+        fn yields_events_once_per_bucket() {
+            let mut response = events_fixture();
+            let duplicate = response.events[0].clone();
+            response.multiday_starting.push(duplicate);
+
+            let buckets: Vec<EventBucket> = response
+                .buckets()
+                .filter(|(_, e)| e.id == response.events[0].id)
+                .map(|(bucket, _)| bucket)
+                .collect();
+            assert_eq!(
+                vec![EventBucket::SingleDay, EventBucket::MultidayStarting],
+                buckets
+            );
+        }
+
+        #[test]
+        fn gets_events_in_bucket() {
+            let response = events_fixture();
+            assert_eq!(response.events, response.events_in(EventBucket::SingleDay));
+            assert_eq!(
+                response.multiday_starting,
+                response.events_in(EventBucket::MultidayStarting)
+            );
+            assert_eq!(
+                response.multiday_ongoing,
+                response.events_in(EventBucket::MultidayOngoing)
+            );
+        }
+    }
+
+    mod unique_events {
+        use super::*;
+
+        #[test]
+        fn lists_all_categories() {
+            let response = events_fixture();
+            let names: Vec<&str> = response
+                .unique_events()
+                .iter()
+                .map(|e| e.name.as_str())
+                .collect();
+            assert_eq!(
+                vec![
+                    "Cinco de Mayo",
+                    "Great Lakes Awareness Day",
+                    "Teacher Appreciation Week",
+                    "Be Kind to Animals Week",
+                    "National Children's Mental Health Awareness Week",
+                ],
+                names
+            );
+        }
+
+        #[test]
+        fn deduplicates_by_id() {
+            let mut response = events_fixture();
+            let duplicate = response.events[1].clone();
+            response.multiday_ongoing.insert(0, duplicate);
+
+            let unique = response.unique_events();
+            assert_eq!(5, unique.len());
+            assert_eq!(
+                1,
+                unique
+                    .iter()
+                    .filter(|e| &*e.id == "50bd02adb1a5fb297657a46a1b6b1082")
+                    .count()
+            );
+            assert!(std::ptr::eq(&response.events[1], unique[1]));
+        }
+    }
+
+    mod eq_ignoring_rate_limit {
+        use super::*;
+
+        fn rate_limit(remaining_month: i32) -> RateLimit {
+            RateLimit {
+                limit_month: 100,
+                remaining_month,
+            }
+        }
+
+        fn summary() -> EventSummary {
+            EventSummary {
+                id: "b80630ae75c35f34c0526173dd999cfc".into(),
+                name: "Cinco de Mayo".into(),
+                url: "https://www.checkiday.com/b80630ae75c35f34c0526173dd999cfc/cinco-de-mayo"
+                    .into(),
+                score: None,
+            }
+        }
+
+        fn events_response(remaining_month: i32) -> GetEventsResponse {
+            GetEventsResponse {
+                adult: false,
+                date: DateOrTimestamp::Date("05/05/2025".into()),
+                timezone: "America/Chicago".into(),
+                events: vec![summary()],
+                multiday_starting: vec![],
+                multiday_ongoing: vec![],
+                rate_limit: rate_limit(remaining_month),
+            }
+        }
+
+        fn search_response(remaining_month: i32) -> SearchResponse {
+            SearchResponse {
+                query: "mayo".into(),
+                adult: false,
+                events: vec![summary()],
+                rate_limit: rate_limit(remaining_month),
+            }
+        }
+
+        fn event_info_response(remaining_month: i32) -> GetEventInfoResponse {
+            GetEventInfoResponse {
+                event: EventInfo {
+                    id: "b80630ae75c35f34c0526173dd999cfc".into(),
+                    name: "Cinco de Mayo".into(),
+                    url: "https://www.checkiday.com/b80630ae75c35f34c0526173dd999cfc/cinco-de-mayo"
+                        .into(),
+                    adult: false,
+                    alternate_names: vec![],
+                    hashtags: None,
+                    image: None,
+                    sources: None,
+                    description: None,
+                    how_to_observe: None,
+                    patterns: None,
+                    occurrences: None,
+                    founders: None,
+                    analytics: None,
+                    tags: None,
+                },
+                rate_limit: rate_limit(remaining_month),
+            }
+        }
+
+        #[test]
+        fn events_differing_only_in_rate_limit() {
+            let a = events_response(88);
+            let b = events_response(87);
+            assert!(a != b);
+            assert!(a.eq_ignoring_rate_limit(&b));
+        }
+
+        #[test]
+        fn events_differing_in_other_fields() {
+            let a = events_response(88);
+            let mut b = events_response(88);
+            b.adult = true;
+            assert!(!a.eq_ignoring_rate_limit(&b));
+        }
+
+        #[test]
+        fn event_info_differing_only_in_rate_limit() {
+            let a = event_info_response(88);
+            let b = event_info_response(87);
+            assert!(a != b);
+            assert!(a.eq_ignoring_rate_limit(&b));
+        }
+
+        #[test]
+        fn search_differing_only_in_rate_limit() {
+            let a = search_response(88);
+            let b = search_response(87);
+            assert!(a != b);
+            assert!(a.eq_ignoring_rate_limit(&b));
+
+            let mut c = search_response(88);
+            c.query = "cinco".into();
+            assert!(!a.eq_ignoring_rate_limit(&c));
+        }
+    }
+}
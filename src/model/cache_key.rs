@@ -0,0 +1,323 @@
+//! Canonical keys identifying requests, e.g. for caching their responses.
+
+use std::collections::HashMap;
+
+use super::{GetEventInfoRequest, GetEventsRequest, SearchRequest, Timezone};
+
+impl GetEventsRequest {
+    /// A canonical key for this request, e.g.
+    /// `events|adult=false|date=2024-12-25|timezone=America/Chicago`.
+    ///
+    /// Requests the API treats the same get the same key: unset fields are
+    /// replaced by the API's defaults, and `extra_params` are sorted (replacing
+    /// modeled parameters only with `allow_override`). The client's
+    /// [`default_adult`](crate::ClientBuilder::default_adult) and
+    /// [`default_timezone`](crate::ClientBuilder::default_timezone) are not
+    /// known here, so only share keys between clients with the same defaults.
+    pub fn cache_key(&self) -> String {
+        let adult = self.adult.unwrap_or(Self::DEFAULT_ADULT).to_string();
+        let timezone = self
+            .timezone
+            .as_ref()
+            .map_or(Self::DEFAULT_TIMEZONE, Timezone::as_str);
+        let mut params = vec![("adult", adult.as_str()), ("timezone", timezone)];
+        if let Some(date) = &self.date {
+            params.push(("date", date));
+        }
+        key("events", params, &self.extra_params, self.allow_override)
+    }
+
+    /// A stable 64-bit hash of [`cache_key`](Self::cache_key).
+    pub fn cache_key_hash(&self) -> u64 {
+        fnv1a(&self.cache_key())
+    }
+}
+
+impl GetEventInfoRequest {
+    /// A canonical key for this request, e.g. `event|end=2026|id=abc|start=2024`.
+    ///
+    /// `extra_params` are sorted, replacing modeled parameters only with
+    /// `allow_override`. An unset `start` or `end` is relative to the current year, so it's
+    /// left out rather than resolved.
+    pub fn cache_key(&self) -> String {
+        let start = self.start.map(|start| start.to_string());
+        let end = self.end.map(|end| end.to_string());
+        let mut params = vec![("id", self.id.as_str())];
+        if let Some(start) = &start {
+            params.push(("start", start));
+        }
+        if let Some(end) = &end {
+            params.push(("end", end));
+        }
+        key("event", params, &self.extra_params, self.allow_override)
+    }
+
+    /// A stable 64-bit hash of [`cache_key`](Self::cache_key).
+    pub fn cache_key_hash(&self) -> u64 {
+        fnv1a(&self.cache_key())
+    }
+
+    /// Whether `key` is the [`cache_key`](Self::cache_key) of a request for
+    /// the Event `id`, of any range.
+    pub(crate) fn is_cache_key_of(key: &str, id: &str) -> bool {
+        let id = format!("id={}", escape(id));
+        let mut params = key.split('|');
+        params.next() == Some("event") && params.any(|param| param == id)
+    }
+}
+
+impl SearchRequest {
+    /// A canonical key for this request, e.g. `search|adult=false|query=cat`.
+    ///
+    /// An unset `adult` is replaced by the API's default, and `extra_params`
+    /// are sorted (replacing modeled parameters only with `allow_override`).
+    /// The client's
+    /// [`default_adult`](crate::ClientBuilder::default_adult) is not known
+    /// here, so only share keys between clients with the same default.
+    pub fn cache_key(&self) -> String {
+        let adult = self.adult.unwrap_or(Self::DEFAULT_ADULT).to_string();
+        let params = vec![("adult", adult.as_str()), ("query", self.query.as_str())];
+        key("search", params, &self.extra_params, self.allow_override)
+    }
+
+    /// A stable 64-bit hash of [`cache_key`](Self::cache_key).
+    pub fn cache_key_hash(&self) -> u64 {
+        fnv1a(&self.cache_key())
+    }
+}
+
+fn key<'a>(
+    endpoint: &str,
+    mut params: Vec<(&'a str, &'a str)>,
+    extra: &'a HashMap<String, String>,
+    allow_override: bool,
+) -> String {
+    for (name, value) in extra {
+        match params.iter_mut().find(|(typed, _)| typed == name) {
+            Some(param) if allow_override => param.1 = value,
+            Some(_) => {}
+            None => params.push((name, value)),
+        }
+    }
+    params.sort();
+
+    let mut key = endpoint.to_string();
+    for (name, value) in params {
+        key.push('|');
+        key.push_str(&escape(name));
+        key.push('=');
+        key.push_str(&escape(value));
+    }
+    key
+}
+
+/// Escapes the separators, so that distinct params can't produce the same key.
+fn escape(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('|', "%7C")
+        .replace('=', "%3D")
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is the same across Rust
+/// versions and platforms, so hashes can be persisted.
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(adult: Option<bool>, timezone: Option<&str>) -> GetEventsRequest {
+        GetEventsRequest {
+            date: Some("2024-12-25".into()),
+            adult,
+            timezone: timezone.map(Into::into),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn formats_keys() {
+        assert_eq!(
+            "events|adult=false|date=2024-12-25|timezone=America/Chicago",
+            events(None, None).cache_key()
+        );
+        assert_eq!(
+            "event|end=2026|id=abc|start=2024",
+            GetEventInfoRequest {
+                id: "abc".into(),
+                start: Some(2024),
+                end: Some(2026),
+                ..Default::default()
+            }
+            .cache_key()
+        );
+        assert_eq!(
+            "search|adult=true|query=cat",
+            SearchRequest {
+                query: "cat".into(),
+                adult: Some(true),
+                ..Default::default()
+            }
+            .cache_key()
+        );
+    }
+
+    #[test]
+    fn matches_event_info_keys_by_id() {
+        let info = |id: &str, start| GetEventInfoRequest {
+            id: id.into(),
+            start,
+            ..Default::default()
+        };
+        assert!(GetEventInfoRequest::is_cache_key_of(
+            &info("abc", None).cache_key(),
+            "abc"
+        ));
+        assert!(GetEventInfoRequest::is_cache_key_of(
+            &info("abc", Some(2024)).cache_key(),
+            "abc"
+        ));
+        assert!(GetEventInfoRequest::is_cache_key_of(
+            &info("a|b=c", None).cache_key(),
+            "a|b=c"
+        ));
+        assert!(!GetEventInfoRequest::is_cache_key_of(
+            &info("abcd", None).cache_key(),
+            "abc"
+        ));
+        assert!(!GetEventInfoRequest::is_cache_key_of(
+            "search|adult=false|query=x|id=abc",
+            "abc"
+        ));
+    }
+
+    #[test]
+    fn normalizes_defaults() {
+        let a = events(None, None);
+        let b = events(Some(false), Some("America/Chicago"));
+        assert_eq!(a.cache_key(), b.cache_key());
+        assert_eq!(a.cache_key_hash(), b.cache_key_hash());
+
+        let a = SearchRequest {
+            query: "cat".into(),
+            ..Default::default()
+        };
+        let b = SearchRequest {
+            query: "cat".into(),
+            adult: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn distinguishes_requests() {
+        let base = events(None, None);
+        let others = [
+            events(Some(true), None),
+            events(None, Some("America/New_York")),
+            GetEventsRequest {
+                date: None,
+                ..events(None, None)
+            },
+            GetEventsRequest {
+                extra_params: HashMap::from([("beta".into(), "1".into())]),
+                ..events(None, None)
+            },
+        ];
+        for other in others {
+            assert_ne!(base.cache_key(), other.cache_key(), "{other:?}");
+            assert_ne!(base.cache_key_hash(), other.cache_key_hash(), "{other:?}");
+        }
+
+        let info = |start| GetEventInfoRequest {
+            id: "abc".into(),
+            start,
+            ..Default::default()
+        };
+        assert_ne!(info(None).cache_key(), info(Some(2024)).cache_key());
+    }
+
+    #[test]
+    fn sorts_extra_params_and_skips_overridden_ones() {
+        let request = |extra: &[(&str, &str)]| SearchRequest {
+            query: "cat".into(),
+            extra_params: extra
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        assert_eq!(
+            "search|adult=false|b=2|query=cat|z=1",
+            request(&[("z", "1"), ("b", "2"), ("query", "dog")]).cache_key()
+        );
+    }
+
+    #[test]
+    fn ignores_the_order_parameters_are_set_in() {
+        let mut extra = HashMap::new();
+        extra.insert("beta".to_string(), "1".to_string());
+        extra.insert("alpha".to_string(), "2".to_string());
+        let a = GetEventsRequest {
+            date: Some("2024-12-25".into()),
+            timezone: Some("America/New_York".into()),
+            extra_params: extra,
+            ..Default::default()
+        };
+
+        let mut extra = HashMap::new();
+        extra.insert("alpha".to_string(), "2".to_string());
+        extra.insert("beta".to_string(), "1".to_string());
+        let b = GetEventsRequest {
+            extra_params: extra,
+            timezone: Some("America/New_York".into()),
+            adult: Some(false),
+            date: Some("2024-12-25".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "events|adult=false|alpha=2|beta=1|date=2024-12-25|timezone=America/New_York",
+            a.cache_key()
+        );
+        assert_eq!(a.cache_key(), b.cache_key());
+        assert_eq!(a.cache_key_hash(), b.cache_key_hash());
+    }
+
+    #[test]
+    fn applies_allowed_overrides() {
+        let request = SearchRequest {
+            query: "cat".into(),
+            extra_params: HashMap::from([("adult".into(), "true".into())]),
+            allow_override: true,
+            ..Default::default()
+        };
+        assert_eq!("search|adult=true|query=cat", request.cache_key());
+    }
+
+    #[test]
+    fn escapes_separators() {
+        let a = SearchRequest {
+            query: "a|adult=true".into(),
+            ..Default::default()
+        };
+        let b = SearchRequest {
+            query: "a".into(),
+            extra_params: HashMap::from([("adult".into(), "true".into())]),
+            ..Default::default()
+        };
+        assert_eq!("search|adult=false|query=a%7Cadult%3Dtrue", a.cache_key());
+        assert_ne!(a.cache_key(), b.cache_key());
+    }
+
+    #[test]
+    fn hashes_are_stable() {
+        assert_eq!(0xcbf29ce484222325, fnv1a(""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a("a"));
+    }
+}
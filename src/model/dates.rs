@@ -0,0 +1,462 @@
+use chrono::{DateTime, Datelike, Days, NaiveDate, SecondsFormat, Utc};
+
+use super::{DateOrTimestamp, EventInfo, Occurrence};
+#[cfg(feature = "chrono-tz")]
+use super::{GetEventsRequest, Timezone};
+use crate::Error;
+
+/// A [`DateOrTimestamp`] parsed into chrono types.
+///
+/// Values that can't be parsed are kept as [`ParsedDate::Unparsed`] so no
+/// data is lost.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedDate {
+    /// A calendar date
+    Date(NaiveDate),
+    /// A point in time
+    Timestamp(DateTime<Utc>),
+    /// The original value, which could not be parsed
+    Unparsed(String),
+}
+
+impl ParsedDate {
+    /// The calendar date, if parsed. Timestamps are converted to their UTC date.
+    pub fn date(&self) -> Option<NaiveDate> {
+        match self {
+            ParsedDate::Date(date) => Some(*date),
+            ParsedDate::Timestamp(timestamp) => Some(timestamp.date_naive()),
+            ParsedDate::Unparsed(_) => None,
+        }
+    }
+}
+
+impl DateOrTimestamp {
+    /// Parses the date or timestamp without ever failing.
+    ///
+    /// Accepts `MM/DD/YYYY` and `YYYY-MM-DD` dates and Unix timestamps (in
+    /// seconds, as a number or a string). Anything else is returned as
+    /// [`ParsedDate::Unparsed`].
+    pub fn parse_flexible(&self) -> ParsedDate {
+        match self {
+            DateOrTimestamp::Date(date) => parse_date_str(date),
+            DateOrTimestamp::Timestamp(timestamp) => parse_timestamp(*timestamp)
+                .unwrap_or_else(|| ParsedDate::Unparsed(timestamp.to_string())),
+        }
+    }
+
+    /// Formats the value as ISO 8601: `YYYY-MM-DD` for dates, and
+    /// `YYYY-MM-DDTHH:MM:SSZ` (in UTC) for timestamps.
+    ///
+    /// Fails with [`Error::InvalidDate`] if the value can't be parsed.
+    pub fn to_iso8601(&self) -> Result<String, Error> {
+        match self.parse_flexible() {
+            ParsedDate::Date(date) => Ok(date.format("%Y-%m-%d").to_string()),
+            ParsedDate::Timestamp(timestamp) => {
+                Ok(timestamp.to_rfc3339_opts(SecondsFormat::Secs, true))
+            }
+            ParsedDate::Unparsed(value) => Err(Error::InvalidDate(value)),
+        }
+    }
+
+    /// Formats the value with a [`chrono` format string](chrono::format::strftime).
+    ///
+    /// Timestamps are converted to the IANA timezone `tz` (UTC by default).
+    /// Dates have no time of day, so they are formatted as midnight in `tz`.
+    /// Fails if the value, the format string, or the timezone is invalid.
+    #[cfg(feature = "chrono-tz")]
+    pub fn format(&self, fmt: &str, tz: Option<&str>) -> Result<String, Error> {
+        use chrono::{format::StrftimeItems, TimeZone};
+        use std::fmt::Write;
+
+        let tz: chrono_tz::Tz = match tz {
+            Some(tz) => tz.parse().map_err(|_| Error::InvalidTimezone(tz.into()))?,
+            None => chrono_tz::UTC,
+        };
+        let datetime = match self.parse_flexible() {
+            ParsedDate::Date(date) => tz
+                .from_local_datetime(&date.and_time(Default::default()))
+                .earliest()
+                .ok_or_else(|| Error::InvalidDate(date.to_string()))?,
+            ParsedDate::Timestamp(timestamp) => timestamp.with_timezone(&tz),
+            ParsedDate::Unparsed(value) => return Err(Error::InvalidDate(value)),
+        };
+
+        let items = StrftimeItems::new(fmt)
+            .parse()
+            .map_err(|_| Error::InvalidFormat(fmt.into()))?;
+        let mut formatted = String::new();
+        write!(formatted, "{}", datetime.format_with_items(items.iter()))
+            .map_err(|_| Error::InvalidFormat(fmt.into()))?;
+        Ok(formatted)
+    }
+}
+
+fn parse_date_str(date: &str) -> ParsedDate {
+    let trimmed = date.trim();
+    for format in ["%m/%d/%Y", "%Y-%m-%d"] {
+        if let Ok(parsed) = NaiveDate::parse_from_str(trimmed, format) {
+            return ParsedDate::Date(parsed);
+        }
+    }
+    trimmed
+        .parse::<i64>()
+        .ok()
+        .and_then(parse_timestamp)
+        .unwrap_or_else(|| ParsedDate::Unparsed(date.into()))
+}
+
+fn parse_timestamp(timestamp: i64) -> Option<ParsedDate> {
+    DateTime::from_timestamp(timestamp, 0).map(ParsedDate::Timestamp)
+}
+
+#[cfg(feature = "chrono-tz")]
+impl GetEventsRequest {
+    /// Sets `date` to the day a Unix timestamp (in seconds) falls on in the
+    /// request's timezone, or [`DEFAULT_TIMEZONE`](Self::DEFAULT_TIMEZONE).
+    ///
+    /// The client's [`default_timezone`](crate::ClientBuilder::default_timezone)
+    /// isn't known here, so set `timezone` first when relying on it. Fails if
+    /// the timezone or the timestamp is invalid.
+    pub fn with_timestamp(mut self, timestamp: i64) -> Result<Self, Error> {
+        let tz = self
+            .timezone
+            .as_ref()
+            .map_or(Self::DEFAULT_TIMEZONE, Timezone::as_str);
+        let date = DateOrTimestamp::Timestamp(timestamp).format("%m/%d/%Y", Some(tz))?;
+        self.date = Some(date);
+        Ok(self)
+    }
+}
+
+impl EventInfo {
+    /// The Occurrences from `start_year` to `end_year`, inclusive.
+    ///
+    /// Timestamps count towards their year in UTC. Occurrences whose date
+    /// can't be parsed are skipped.
+    pub fn occurrences_between(&self, start_year: i32, end_year: i32) -> Vec<&Occurrence> {
+        self.occurrences
+            .iter()
+            .flatten()
+            .filter(|occurrence| {
+                occurrence
+                    .date
+                    .parse_flexible()
+                    .date()
+                    .is_some_and(|date| (start_year..=end_year).contains(&date.year()))
+            })
+            .collect()
+    }
+
+    /// Whether any Occurrence spans `date`, i.e. starts on or before it and
+    /// lasts until at least that day.
+    ///
+    /// Occurrences last at least one day. Timestamps start on their UTC date,
+    /// and Occurrences whose date can't be parsed are skipped.
+    pub fn is_observed_on(&self, date: NaiveDate) -> bool {
+        self.occurrences.iter().flatten().any(|occurrence| {
+            let Some(start) = occurrence.date.parse_flexible().date() else {
+                return false;
+            };
+            let length = Days::new(occurrence.length.max(1) as u64);
+            start <= date
+                && start
+                    .checked_add_days(length)
+                    .map_or(true, |end| date < end)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixtures::event_info;
+
+    fn ymd(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn parses_us_dates() {
+        assert_eq!(
+            ParsedDate::Date(ymd(2020, 8, 8)),
+            DateOrTimestamp::Date("08/08/2020".into()).parse_flexible()
+        );
+    }
+
+    #[test]
+    fn parses_iso_dates() {
+        assert_eq!(
+            ParsedDate::Date(ymd(2020, 8, 8)),
+            DateOrTimestamp::Date("2020-08-08".into()).parse_flexible()
+        );
+    }
+
+    #[test]
+    fn parses_us_and_iso_dates_alike() {
+        for (us, iso) in [
+            ("08/08/2020", "2020-08-08"),
+            ("02/29/2024", "2024-02-29"),
+            ("12/31/1999", "1999-12-31"),
+        ] {
+            let us = DateOrTimestamp::Date(us.into());
+            let iso = DateOrTimestamp::Date(iso.into());
+            assert!(us.parse_flexible().date().is_some(), "{us:?}");
+            assert_eq!(us.parse_flexible(), iso.parse_flexible());
+            assert_eq!(us.to_iso8601().unwrap(), iso.to_iso8601().unwrap());
+        }
+    }
+
+    #[test]
+    fn parses_timestamps() {
+        let parsed = DateOrTimestamp::Timestamp(1734772794).parse_flexible();
+        assert_eq!(
+            ParsedDate::Timestamp(DateTime::from_timestamp(1734772794, 0).unwrap()),
+            parsed
+        );
+        assert_eq!(Some(ymd(2024, 12, 21)), parsed.date());
+
+        let parsed = DateOrTimestamp::Timestamp(-12345).parse_flexible();
+        assert_eq!(Some(ymd(1969, 12, 31)), parsed.date());
+    }
+
+    #[test]
+    fn parses_timestamp_strings() {
+        assert_eq!(
+            DateOrTimestamp::Timestamp(1682652947).parse_flexible(),
+            DateOrTimestamp::Date("1682652947".into()).parse_flexible()
+        );
+    }
+
+    #[test]
+    fn keeps_out_of_range_timestamps() {
+        assert_eq!(
+            ParsedDate::Unparsed(i64::MAX.to_string()),
+            DateOrTimestamp::Timestamp(i64::MAX).parse_flexible()
+        );
+        assert_eq!(
+            ParsedDate::Unparsed(i64::MIN.to_string()),
+            DateOrTimestamp::Timestamp(i64::MIN).parse_flexible()
+        );
+    }
+
+    #[test]
+    fn never_panics_on_malformed_dates() {
+        let malformed = [
+            "",
+            " ",
+            "today",
+            "13/40/2020",
+            "02/30/2021",
+            "00/00/0000",
+            "8/8",
+            "08/08/",
+            "//",
+            "2020-13-01",
+            "2020-02-30",
+            "2020-08-08T00:00:00Z",
+            "99999999999999999999999",
+            "-99999999999999999999999",
+            "9223372036854775807",
+            "1e10",
+            "0x1F",
+            "08/08/2020 extra",
+            "０８/０８/２０２０",
+            "🎉/🎉/🎉",
+            "\0",
+            "\u{202e}08/08/2020",
+        ];
+
+        for date in malformed {
+            let parsed = DateOrTimestamp::Date(date.into()).parse_flexible();
+            assert_eq!(ParsedDate::Unparsed(date.into()), parsed, "{date:?}");
+            assert_eq!(None, parsed.date());
+        }
+    }
+
+    #[test]
+    fn formats_iso8601() {
+        assert_eq!(
+            "2020-08-08",
+            DateOrTimestamp::Date("08/08/2020".into())
+                .to_iso8601()
+                .unwrap()
+        );
+        assert_eq!(
+            "1969-12-31T20:34:15Z",
+            DateOrTimestamp::Timestamp(-12345).to_iso8601().unwrap()
+        );
+        assert_eq!(
+            "Invalid date: someday",
+            DateOrTimestamp::Date("someday".into())
+                .to_iso8601()
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    mod format {
+        use super::*;
+
+        #[test]
+        fn sets_date_from_timestamp() {
+            // 2024-12-21T09:19:54Z
+            let request = GetEventsRequest::default()
+                .with_timestamp(1734772794)
+                .unwrap();
+            assert_eq!(Some("12/21/2024".into()), request.date);
+
+            // 2024-12-21T02:00:00Z is still the 20th in Chicago.
+            let request = GetEventsRequest::default()
+                .with_timestamp(1734746400)
+                .unwrap();
+            assert_eq!(Some("12/20/2024".into()), request.date);
+
+            let request = GetEventsRequest {
+                timezone: Some("Asia/Tokyo".into()),
+                ..Default::default()
+            }
+            .with_timestamp(1734746400)
+            .unwrap();
+            assert_eq!(Some("12/21/2024".into()), request.date);
+        }
+
+        #[test]
+        fn rejects_invalid_timestamps() {
+            assert!(matches!(
+                GetEventsRequest::default().with_timestamp(i64::MAX),
+                Err(Error::InvalidDate(_))
+            ));
+            let request = GetEventsRequest {
+                timezone: Some("Mars/Olympus_Mons".into()),
+                ..Default::default()
+            };
+            assert!(matches!(
+                request.with_timestamp(0),
+                Err(Error::InvalidTimezone(_))
+            ));
+        }
+
+        #[test]
+        fn formats_dates() {
+            let date = DateOrTimestamp::Date("05/05/2025".into());
+            assert_eq!("05.05.2025", date.format("%d.%m.%Y", None).unwrap());
+            assert_eq!(
+                "2025-05-05T00:00:00+02:00",
+                date.format("%Y-%m-%dT%H:%M:%S%:z", Some("Europe/Berlin"))
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn formats_timestamps_in_timezone() {
+            let timestamp = DateOrTimestamp::Timestamp(1734772794);
+            assert_eq!(
+                "21.12.2024 09:19",
+                timestamp.format("%d.%m.%Y %H:%M", None).unwrap()
+            );
+            assert_eq!(
+                "21.12.2024 10:19 CET",
+                timestamp
+                    .format("%d.%m.%Y %H:%M %Z", Some("Europe/Berlin"))
+                    .unwrap()
+            );
+            assert_eq!(
+                "20.12.2024",
+                timestamp
+                    .format("%d.%m.%Y", Some("Pacific/Honolulu"))
+                    .unwrap()
+            );
+        }
+
+        #[test]
+        fn fails_on_invalid_input() {
+            let date = DateOrTimestamp::Date("05/05/2025".into());
+            assert!(matches!(
+                date.format("%Q", None),
+                Err(Error::InvalidFormat(_))
+            ));
+            assert!(matches!(
+                date.format("%Y", Some("Mars/Olympus")),
+                Err(Error::InvalidTimezone(_))
+            ));
+            assert!(matches!(
+                DateOrTimestamp::Date("someday".into()).format("%Y", None),
+                Err(Error::InvalidDate(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn filters_occurrences_by_year() {
+        let event = event_info();
+
+        let dates: Vec<&DateOrTimestamp> = event
+            .occurrences_between(2024, 2024)
+            .into_iter()
+            .map(|o| &o.date)
+            .collect();
+        assert_eq!(
+            vec![
+                &DateOrTimestamp::Date("08/08/2024".into()),
+                &DateOrTimestamp::Timestamp(1734772794),
+            ],
+            dates
+        );
+
+        assert_eq!(1, event.occurrences_between(2022, 2022).len());
+        assert_eq!(6, event.occurrences_between(2020, 2030).len());
+        assert_eq!(1, event.occurrences_between(1969, 1969).len());
+        assert!(event.occurrences_between(2030, 2020).is_empty());
+    }
+
+    #[test]
+    fn skips_unparseable_occurrences() {
+        let mut event = event_info();
+        event.occurrences = Some(vec![Occurrence {
+            date: DateOrTimestamp::Date("someday".into()),
+            length: 1,
+        }]);
+        assert!(event.occurrences_between(i32::MIN, i32::MAX).is_empty());
+
+        event.occurrences = None;
+        assert!(event.occurrences_between(i32::MIN, i32::MAX).is_empty());
+    }
+
+    #[test]
+    fn observes_multi_day_events() {
+        let mut event = event_info();
+        event.occurrences = Some(vec![Occurrence {
+            date: DateOrTimestamp::Date("08/08/2020".into()),
+            length: 7,
+        }]);
+
+        assert!(!event.is_observed_on(ymd(2020, 8, 7)));
+        assert!(event.is_observed_on(ymd(2020, 8, 8)));
+        assert!(event.is_observed_on(ymd(2020, 8, 11)));
+        assert!(event.is_observed_on(ymd(2020, 8, 14)));
+        assert!(!event.is_observed_on(ymd(2020, 8, 15)));
+        assert!(!event.is_observed_on(ymd(2021, 8, 8)));
+    }
+
+    #[test]
+    fn observes_single_day_events() {
+        let mut event = event_info();
+        event.occurrences = Some(vec![
+            Occurrence {
+                date: DateOrTimestamp::Date("someday".into()),
+                length: 7,
+            },
+            Occurrence {
+                date: DateOrTimestamp::Date("2020-08-08".into()),
+                length: 0,
+            },
+        ]);
+
+        assert!(event.is_observed_on(ymd(2020, 8, 8)));
+        assert!(!event.is_observed_on(ymd(2020, 8, 9)));
+
+        event.occurrences = None;
+        assert!(!event.is_observed_on(ymd(2020, 8, 8)));
+    }
+}
@@ -0,0 +1,11 @@
+//! Loaders for the response fixtures in `testdata/`, shared by the model tests.
+
+use super::{EventInfo, GetEventInfoResponse};
+
+/// The Event of the default get_event_info fixture.
+pub(crate) fn event_info() -> EventInfo {
+    let json = std::fs::read_to_string("testdata/getEventInfo-default.json").unwrap();
+    serde_json::from_str::<GetEventInfoResponse>(&json)
+        .unwrap()
+        .event
+}
@@ -0,0 +1,256 @@
+use chrono::{Days, NaiveDate};
+
+use super::{EventInfo, GetEventsResponse};
+
+static PRODUCT_ID: &str = "-//Westy92 LLC//Holiday and Event API Rust//EN";
+
+impl GetEventsResponse {
+    /// Exports the Events as an iCalendar (.ics) document.
+    ///
+    /// Each Event (including multi-day Events) becomes an all-day `VEVENT` on
+    /// the response's Date, since the events response doesn't include how
+    /// long multi-day Events last (see [`EventInfo::to_ical`] for that).
+    /// Events are exported in [`unique_events`](Self::unique_events) order,
+    /// once each. If the Date can't be parsed, no `VEVENT`s are emitted.
+    pub fn to_ical(&self) -> String {
+        let mut calendar = Calendar::new();
+        if let Some(date) = self.date.parse_flexible().date() {
+            for event in self.unique_events() {
+                calendar.event(CalendarEvent {
+                    id: &event.id,
+                    start: date,
+                    length: 1,
+                    summary: &event.name,
+                    description: None,
+                    url: event.url.as_str(),
+                });
+            }
+        }
+        calendar.finish()
+    }
+}
+
+impl EventInfo {
+    /// Exports the Event's Occurrences as an iCalendar (.ics) document.
+    ///
+    /// Each Occurrence becomes an all-day `VEVENT` spanning its length.
+    /// Occurrences whose date can't be parsed are skipped.
+    pub fn to_ical(&self) -> String {
+        let mut calendar = Calendar::new();
+        let description = self.description.as_ref().and_then(|d| d.text.as_deref());
+        for occurrence in self.occurrences.iter().flatten() {
+            let Some(date) = occurrence.date.parse_flexible().date() else {
+                continue;
+            };
+            calendar.event(CalendarEvent {
+                id: &self.id,
+                start: date,
+                length: occurrence.length,
+                summary: &self.name,
+                description,
+                url: self.url.as_str(),
+            });
+        }
+        calendar.finish()
+    }
+}
+
+pub(crate) struct CalendarEvent<'a> {
+    pub id: &'a str,
+    pub start: NaiveDate,
+    pub length: i32,
+    pub summary: &'a str,
+    pub description: Option<&'a str>,
+    pub url: &'a str,
+}
+
+pub(crate) struct Calendar {
+    out: String,
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        let mut calendar = Self { out: String::new() };
+        calendar.line("BEGIN:VCALENDAR");
+        calendar.line("VERSION:2.0");
+        calendar.line(&format!("PRODID:{PRODUCT_ID}"));
+        calendar.line("CALSCALE:GREGORIAN");
+        calendar
+    }
+
+    pub fn event(&mut self, event: CalendarEvent) {
+        let start = event.start.format("%Y%m%d");
+        // DTEND is exclusive for all-day events.
+        let end = event
+            .start
+            .checked_add_days(Days::new(event.length.max(1) as u64))
+            .unwrap_or(event.start);
+
+        self.line("BEGIN:VEVENT");
+        self.line(&format!("UID:{}-{start}@checkiday.com", event.id));
+        self.line(&format!("DTSTAMP:{start}T000000Z"));
+        self.line(&format!("DTSTART;VALUE=DATE:{start}"));
+        self.line(&format!("DTEND;VALUE=DATE:{}", end.format("%Y%m%d")));
+        self.line(&format!("SUMMARY:{}", escape(event.summary)));
+        if let Some(description) = event.description {
+            self.line(&format!("DESCRIPTION:{}", escape(description)));
+        }
+        self.line(&format!("URL:{}", event.url));
+        self.line("END:VEVENT");
+    }
+
+    pub fn finish(mut self) -> String {
+        self.line("END:VCALENDAR");
+        self.out
+    }
+
+    /// Writes a content line, folding it at 75 octets (RFC 5545 section 3.1).
+    fn line(&mut self, line: &str) {
+        let mut width = 0;
+        for c in line.chars() {
+            if width + c.len_utf8() > 75 {
+                self.out.push_str("\r\n ");
+                width = 1;
+            }
+            self.out.push(c);
+            width += c.len_utf8();
+        }
+        self.out.push_str("\r\n");
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{fixtures::event_info, DateOrTimestamp, Occurrence};
+
+    fn events_response() -> GetEventsResponse {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn exports_one_vevent_per_event() {
+        let ical = events_response().to_ical();
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(5, ical.matches("BEGIN:VEVENT").count());
+        assert_eq!(5, ical.matches("END:VEVENT").count());
+        assert!(ical.contains("SUMMARY:Cinco de Mayo\r\n"));
+        assert!(ical.contains("DTSTART;VALUE=DATE:20250505\r\n"));
+        assert!(ical.contains("DTEND;VALUE=DATE:20250506\r\n"));
+        assert!(ical.contains("UID:b80630ae75c35f34c0526173dd999cfc-20250505@checkiday.com\r\n"));
+    }
+
+    #[test]
+    fn exports_events_in_several_buckets_once() {
+        let mut response = events_response();
+        response.multiday_ongoing.push(response.events[0].clone());
+        let ical = response.to_ical();
+
+        assert_eq!(5, ical.matches("BEGIN:VEVENT").count());
+        assert_eq!(
+            1,
+            ical.matches("UID:b80630ae75c35f34c0526173dd999cfc-20250505@checkiday.com\r\n")
+                .count()
+        );
+    }
+
+    #[test]
+    fn exports_timestamp_dates() {
+        let mut response = events_response();
+        response.date = DateOrTimestamp::Timestamp(1682652947);
+        assert!(response
+            .to_ical()
+            .contains("DTSTART;VALUE=DATE:20230428\r\n"));
+    }
+
+    #[test]
+    fn skips_events_with_unparseable_date() {
+        let mut response = events_response();
+        response.date = DateOrTimestamp::Date("someday".into());
+        let ical = response.to_ical();
+
+        assert!(ical.contains("BEGIN:VCALENDAR"));
+        assert!(!ical.contains("BEGIN:VEVENT"));
+    }
+
+    #[test]
+    fn exports_one_vevent_per_occurrence() {
+        let ical = event_info().to_ical();
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(7, ical.matches("BEGIN:VEVENT").count());
+        assert_eq!(7, ical.matches("SUMMARY:International Cat Day\r\n").count());
+        assert_eq!(
+            7,
+            ical.matches("DESCRIPTION:International Cat Day celebrates love for cats...\r\n")
+                .count()
+        );
+        assert!(ical.contains("DTSTART;VALUE=DATE:20200808\r\nDTEND;VALUE=DATE:20200809\r\n"));
+        // Timestamp occurrences
+        assert!(ical.contains("DTSTART;VALUE=DATE:20241221\r\nDTEND;VALUE=DATE:20241222\r\n"));
+        assert!(ical.contains("DTSTART;VALUE=DATE:19691231\r\nDTEND;VALUE=DATE:19700107\r\n"));
+    }
+
+    #[test]
+    fn skips_unparseable_occurrences() {
+        let mut event = event_info();
+        event.description = None;
+        event.occurrences = Some(vec![
+            Occurrence {
+                date: DateOrTimestamp::Date("someday".into()),
+                length: 1,
+            },
+            Occurrence {
+                date: DateOrTimestamp::Date("2020-08-08".into()),
+                length: 1,
+            },
+        ]);
+        let ical = event.to_ical();
+
+        assert_eq!(1, ical.matches("BEGIN:VEVENT").count());
+        assert!(!ical.contains("DESCRIPTION"));
+    }
+
+    #[test]
+    fn exports_no_vevents_without_occurrences() {
+        let mut event = event_info();
+        event.occurrences = None;
+        assert!(!event.to_ical().contains("BEGIN:VEVENT"));
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!("a\\, b\\; c\\\\d\\ne", escape("a, b; c\\d\r\ne"));
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let mut response = events_response();
+        response.events[0].name = "Día ".repeat(40);
+        let ical = response.to_ical();
+
+        for line in ical.split("\r\n") {
+            assert!(line.len() <= 75, "{line:?}");
+        }
+        let unfolded = ical.replace("\r\n ", "");
+        assert!(unfolded.contains(&format!("SUMMARY:{}\r\n", "Día ".repeat(40))));
+    }
+}
@@ -0,0 +1,132 @@
+//! Links to Events, on checkiday.com and in the API.
+//!
+//! An Event's id is its identity, so links can be built from the id alone,
+//! e.g. for deep links that stay valid when an Event is renamed.
+
+use reqwest::Url;
+
+use super::EventSummary;
+use crate::{endpoint_url, params::QueryParams, Endpoint};
+
+/// Where Events are published.
+const WEB_BASE_URL: &str = "https://www.checkiday.com/";
+
+/// The Event's page on checkiday.com, e.g.
+/// `https://www.checkiday.com/<id>/<slug>`.
+///
+/// The slug (the Event's name in the URL) is optional. Without it, the URL is
+/// `https://www.checkiday.com/<id>`, which checkiday.com resolves too.
+pub fn web_url_for_id(id: &str, slug: Option<&str>) -> Url {
+    let mut url = Url::parse(WEB_BASE_URL).unwrap();
+    url.path_segments_mut()
+        .unwrap()
+        .clear()
+        .push(id)
+        .extend(slug.filter(|slug| !slug.is_empty()));
+    url
+}
+
+/// The get_event_info URL of the Event, for the API at `base`, e.g.
+/// [`HolidayEventApi::base_url`](crate::HolidayEventApi::base_url).
+///
+/// Like [`HolidayEventApi::event_info_url`](crate::HolidayEventApi::event_info_url),
+/// it doesn't include the API key.
+pub fn api_event_url(base: &Url, id: &str) -> Url {
+    let mut params = QueryParams::new();
+    params.insert_unique("id", id);
+    endpoint_url(base, Endpoint::EventInfo, &params)
+}
+
+impl EventSummary {
+    /// The Event's page on checkiday.com: its `url`, or one built from its
+    /// id with [`web_url_for_id`] if `url` isn't a valid web URL.
+    pub fn web_url(&self) -> Url {
+        Url::parse(self.url.as_str())
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .unwrap_or_else(|| web_url_for_id(&self.id, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{GetEventInfoRequest, GetEventsResponse},
+        HolidayEventApi,
+    };
+
+    fn events() -> Vec<EventSummary> {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        serde_json::from_str::<GetEventsResponse>(&json)
+            .unwrap()
+            .events
+    }
+
+    /// The slug of a fixture URL, `https://www.checkiday.com/<id>/<slug>`.
+    fn slug(event: &EventSummary) -> &str {
+        event.url.as_str().rsplit('/').next().unwrap()
+    }
+
+    #[test]
+    fn builds_fixture_web_urls() {
+        for event in events() {
+            let url = web_url_for_id(&event.id, Some(slug(&event)));
+            assert_eq!(event.url.as_str(), url.as_str());
+            assert_eq!(url, Url::parse(url.as_str()).unwrap());
+        }
+    }
+
+    #[test]
+    fn builds_web_urls_without_slug() {
+        let event = &events()[0];
+        let expected = format!("https://www.checkiday.com/{}", event.id);
+        assert_eq!(expected, web_url_for_id(&event.id, None).as_str());
+        assert_eq!(expected, web_url_for_id(&event.id, Some("")).as_str());
+    }
+
+    #[test]
+    fn escapes_web_url_segments() {
+        let url = web_url_for_id("a/b?c", Some("d e"));
+        assert_eq!("https://www.checkiday.com/a%2Fb%3Fc/d%20e", url.as_str());
+        assert_eq!(2, url.path_segments().unwrap().count());
+    }
+
+    #[test]
+    fn builds_api_urls_like_the_client() {
+        let api = HolidayEventApi::new("abc123").unwrap();
+        let id = "f90b893ea04939d7456f30c54f68d7b4";
+        let request = GetEventInfoRequest {
+            id: id.into(),
+            ..Default::default()
+        };
+        assert_eq!(
+            api.event_info_url(&request).unwrap(),
+            api_event_url(api.base_url(), id)
+        );
+        assert_eq!(
+            "https://api.apilayer.com/checkiday/event?id=f90b893ea04939d7456f30c54f68d7b4",
+            api_event_url(api.base_url(), id).as_str()
+        );
+    }
+
+    #[test]
+    fn prefers_embedded_web_url() {
+        let event = &events()[0];
+        assert_eq!(event.url.as_str(), event.web_url().as_str());
+    }
+
+    #[test]
+    fn falls_back_to_id_for_web_url() {
+        for url in ["", "not a url", "mailto:cats@example.com"] {
+            let event = EventSummary {
+                url: serde_json::from_value(url.into()).unwrap(),
+                ..events()[0].clone()
+            };
+            assert_eq!(
+                format!("https://www.checkiday.com/{}", event.id),
+                event.web_url().as_str()
+            );
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use super::RichText;
+
+impl RichText {
+    /// The text as Markdown: `markdown` if present, otherwise `html`
+    /// converted to Markdown, otherwise `text` as is.
+    ///
+    /// The conversion handles paragraphs, line breaks, links, emphasis, and
+    /// lists. Other tags are dropped, keeping their contents, except for
+    /// `script` and `style`, which are dropped entirely.
+    pub fn to_markdown(&self) -> Option<String> {
+        self.markdown
+            .clone()
+            .or_else(|| self.html.as_deref().map(html_to_markdown))
+            .or_else(|| self.text.clone())
+    }
+}
+
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    // The href of each open link, innermost last.
+    let mut links: Vec<Option<String>> = vec![];
+    let mut skipping: Option<String> = None;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            if skipping.is_none() {
+                push_text(&mut out, rest);
+            }
+            break;
+        };
+        if skipping.is_none() {
+            push_text(&mut out, &rest[..start]);
+        }
+        let Some(len) = rest[start..].find('>') else {
+            // An unterminated tag is text.
+            if skipping.is_none() {
+                push_text(&mut out, &rest[start..]);
+            }
+            break;
+        };
+        let tag = Tag::parse(&rest[start + 1..start + len]);
+        rest = &rest[start + len + 1..];
+
+        if let Some(skipped) = &skipping {
+            if tag.closing && tag.name == *skipped {
+                skipping = None;
+            }
+            continue;
+        }
+        match (tag.name.as_str(), tag.closing) {
+            ("script" | "style", false) => skipping = Some(tag.name),
+            ("p" | "div" | "ul" | "ol", _) => push_break(&mut out, "\n\n"),
+            ("br", _) => push_break(&mut out, "\n"),
+            ("li", false) => {
+                push_break(&mut out, "\n");
+                out.push_str("- ");
+            }
+            ("li", true) => push_break(&mut out, "\n"),
+            ("em" | "i", _) => out.push('*'),
+            ("strong" | "b", _) => out.push_str("**"),
+            ("a", false) => {
+                let href = tag.href.filter(|href| !href.is_empty());
+                if href.is_some() {
+                    out.push('[');
+                }
+                links.push(href);
+            }
+            ("a", true) => {
+                if let Some(Some(href)) = links.pop() {
+                    let href = href.replace(' ', "%20").replace(')', "%29");
+                    out.push_str(&format!("]({href})"));
+                }
+            }
+            _ => {}
+        }
+    }
+    // Close links left open by malformed HTML.
+    for href in links.into_iter().rev().flatten() {
+        out.push_str(&format!("]({href})"));
+    }
+    out.trim().to_string()
+}
+
+/// An HTML tag, e.g. `a href="https://example.com"` or `/p`.
+struct Tag {
+    name: String,
+    closing: bool,
+    href: Option<String>,
+}
+
+impl Tag {
+    fn parse(tag: &str) -> Self {
+        let tag = tag.trim().trim_end_matches('/');
+        let (closing, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        let (name, attributes) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+        Self {
+            name: name.to_ascii_lowercase(),
+            closing,
+            href: attribute(attributes, "href").map(|href| decode_entities(&href)),
+        }
+    }
+}
+
+/// The value of the attribute `name`, quoted or not.
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    let mut rest = attributes;
+    while let Some(index) = rest.to_ascii_lowercase().find(name) {
+        let starts_attribute = rest[..index]
+            .chars()
+            .last()
+            .map_or(true, char::is_whitespace);
+        rest = &rest[index + name.len()..];
+        let Some(value) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        if !starts_attribute {
+            continue;
+        }
+        let value = value.trim_start();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].split(quote).next().unwrap_or_default(),
+            _ => value.split(char::is_whitespace).next().unwrap_or_default(),
+        };
+        return Some(value.to_string());
+    }
+    None
+}
+
+/// Appends HTML text, collapsing whitespace and escaping Markdown syntax.
+fn push_text(out: &mut String, text: &str) {
+    for c in decode_entities(text).chars() {
+        if c.is_whitespace() {
+            if !out.is_empty() && !out.ends_with(char::is_whitespace) {
+                out.push(' ');
+            }
+            continue;
+        }
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '`') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+}
+
+/// Ends the current line or paragraph, without stacking up blank lines.
+fn push_break(out: &mut String, separator: &str) {
+    let trimmed = out.trim_end_matches(' ').len();
+    out.truncate(trimmed);
+    if out.is_empty() || out.ends_with(separator) {
+        return;
+    }
+    if separator == "\n\n" && out.ends_with('\n') {
+        out.push('\n');
+    } else {
+        out.push_str(separator);
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.into();
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixtures;
+
+    fn html(html: &str) -> RichText {
+        RichText {
+            text: None,
+            html: Some(html.into()),
+            markdown: None,
+        }
+    }
+
+    /// The targets of the Markdown links in `markdown`.
+    fn link_targets(markdown: &str) -> Vec<&str> {
+        markdown
+            .split("](")
+            .skip(1)
+            .filter_map(|rest| rest.split(')').next())
+            .collect()
+    }
+
+    #[test]
+    fn converts_fixture_html() {
+        let event = fixtures::event_info();
+
+        for text in [event.description.unwrap(), event.how_to_observe.unwrap()] {
+            let converted = html(text.html.as_deref().unwrap()).to_markdown().unwrap();
+            let markdown = text.markdown.as_deref().unwrap();
+            assert_eq!(markdown, converted);
+            assert_eq!(link_targets(markdown), link_targets(&converted));
+            assert!(!link_targets(markdown).is_empty());
+        }
+    }
+
+    #[test]
+    fn prefers_markdown_then_html_then_text() {
+        let all = RichText {
+            text: Some("text".into()),
+            html: Some("<p>html</p>".into()),
+            markdown: Some("markdown".into()),
+        };
+        assert_eq!(Some("markdown".into()), all.to_markdown());
+
+        let no_markdown = RichText {
+            markdown: None,
+            ..all.clone()
+        };
+        assert_eq!(Some("html".into()), no_markdown.to_markdown());
+
+        let only_text = RichText {
+            html: None,
+            ..no_markdown
+        };
+        assert_eq!(Some("text".into()), only_text.to_markdown());
+
+        let empty = RichText {
+            text: None,
+            ..only_text
+        };
+        assert_eq!(None, empty.to_markdown());
+    }
+
+    #[test]
+    fn converts_formatting() {
+        assert_eq!(
+            "One *two* **three**\n\nFour\nfive",
+            html("<p>One <em>two</em> <strong>three</strong></p><p>Four<br>five</p>")
+                .to_markdown()
+                .unwrap()
+        );
+        assert_eq!(
+            "Eat:\n\n- pie\n- cake",
+            html("Eat:<ul><li>pie</li><li>cake</li></ul>")
+                .to_markdown()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_link_targets() {
+        assert_eq!(
+            "[a](https://example.com/?a=1&b=2) [b](https://example.com/x%20(y%29) c",
+            html(
+                "<a class=x href='https://example.com/?a=1&amp;b=2'>a</a> \
+                 <A HREF=\"https://example.com/x (y)\">b</A> <a>c</a>"
+            )
+            .to_markdown()
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn escapes_markdown_syntax() {
+        assert_eq!(
+            "1 \\* 2 &lt; \\[3\\]",
+            html("1 * 2 &amp;lt; [3]").to_markdown().unwrap()
+        );
+    }
+
+    #[test]
+    fn handles_malformed_html() {
+        for malformed in [
+            "<",
+            "<p",
+            "</a>",
+            "<a href=>x",
+            "<script>x",
+            "a <b>c",
+            "<a href=\"",
+        ] {
+            html(malformed).to_markdown();
+        }
+        assert_eq!(
+            "Hi [there](https://example.com)",
+            html("<p>Hi <a href=\"https://example.com\">there")
+                .to_markdown()
+                .unwrap()
+        );
+        assert_eq!(
+            "Hi",
+            html("Hi<script>alert(1)</script>").to_markdown().unwrap()
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Helpers for combining the details of several Events, e.g. all Events of a
+//! single day.
+
+use std::collections::HashSet;
+
+use reqwest::Url;
+
+use super::EventInfo;
+
+/// The Events' hashtags, without duplicates.
+///
+/// Hashtags are compared case-insensitively. The first-seen casing is kept,
+/// in the order the hashtags were first seen.
+pub fn merge_hashtags<'a>(events: impl Iterator<Item = &'a EventInfo>) -> Vec<String> {
+    dedupe(
+        events.flat_map(|event| event.hashtags.iter().flatten()),
+        |hashtag| hashtag.to_lowercase(),
+    )
+}
+
+/// The Events' sources, without duplicates.
+///
+/// Sources are compared ignoring their scheme, the case of their host, and a
+/// trailing slash, so `http://Example.com/a/` and `https://example.com/a` are
+/// the same source. The first-seen form is kept, in the order the sources
+/// were first seen.
+pub fn merge_sources<'a>(events: impl Iterator<Item = &'a EventInfo>) -> Vec<String> {
+    dedupe(
+        events.flat_map(|event| event.sources.iter().flatten()),
+        normalize_source,
+    )
+}
+
+fn dedupe<'a>(
+    values: impl Iterator<Item = &'a String>,
+    key: impl Fn(&str) -> String,
+) -> Vec<String> {
+    let mut seen = HashSet::new();
+    values
+        .filter(|value| seen.insert(key(value)))
+        .cloned()
+        .collect()
+}
+
+fn normalize_source(source: &str) -> String {
+    // Url lowercases the host and drops default ports.
+    let url = Url::parse(source).ok().filter(Url::has_host);
+    let normalized = match &url {
+        Some(url) => url
+            .as_str()
+            .split_once("://")
+            .map_or(url.as_str(), |(_, rest)| rest),
+        None => source,
+    };
+    normalized.trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixtures;
+
+    fn event(hashtags: &[&str], sources: &[&str]) -> EventInfo {
+        let mut event = fixtures::event_info();
+        event.hashtags = Some(hashtags.iter().map(|h| h.to_string()).collect());
+        event.sources = Some(sources.iter().map(|s| s.to_string()).collect());
+        event
+    }
+
+    #[test]
+    fn merges_hashtags_case_insensitively() {
+        let events = [
+            event(&["CatDay", "Cats"], &[]),
+            event(&["catday", "Caturday", "CATS"], &[]),
+        ];
+        assert_eq!(
+            vec!["CatDay", "Cats", "Caturday"],
+            merge_hashtags(events.iter())
+        );
+    }
+
+    #[test]
+    fn merges_sources_ignoring_scheme_host_case_and_trailing_slash() {
+        let events = [
+            event(
+                &[],
+                &["https://example.com/cats/", "https://example.com/Dogs"],
+            ),
+            event(
+                &[],
+                &[
+                    "http://EXAMPLE.com/cats",
+                    "https://example.com/dogs",
+                    "https://example.com:8080/cats",
+                    "not a url/",
+                    "not a url",
+                ],
+            ),
+        ];
+        assert_eq!(
+            vec![
+                "https://example.com/cats/",
+                "https://example.com/Dogs",
+                "https://example.com/dogs",
+                "https://example.com:8080/cats",
+                "not a url/",
+            ],
+            merge_sources(events.iter())
+        );
+    }
+
+    #[test]
+    fn skips_missing_values() {
+        let mut empty = event(&[], &[]);
+        empty.hashtags = None;
+        empty.sources = None;
+        let events = [empty, event(&["Cats"], &["https://example.com"])];
+
+        assert_eq!(vec!["Cats"], merge_hashtags(events.iter()));
+        assert_eq!(vec!["https://example.com"], merge_sources(events.iter()));
+    }
+}
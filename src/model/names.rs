@@ -0,0 +1,117 @@
+use super::EventSummary;
+
+impl EventSummary {
+    /// The name with runs of whitespace collapsed into single spaces, e.g. for
+    /// slugs. With the `unicode` feature, it's also in Unicode Normalization
+    /// Form C, so equal names are made of equal code points.
+    pub fn normalized_name(&self) -> String {
+        normalize(&self.name)
+    }
+
+    /// Whether the name equals `needle`, ignoring case, whitespace, and
+    /// typographic apostrophes.
+    ///
+    /// With the `unicode` feature, case is compared with full Unicode case
+    /// folding and accents are ignored, so "Día de los Muertos" matches
+    /// "dia de los muertos". Otherwise only ASCII letters ignore case.
+    pub fn matches_name(&self, needle: &str) -> bool {
+        fold(&self.name) == fold(needle)
+    }
+}
+
+/// `text` with whitespace collapsed and, with the `unicode` feature, in NFC.
+pub(crate) fn normalize(text: &str) -> String {
+    let text = collapse_whitespace(text);
+    #[cfg(feature = "unicode")]
+    let text = unicode_normalization::UnicodeNormalization::nfc(text.as_str()).collect();
+    text
+}
+
+/// `text` folded for comparisons: without case, accents (with the `unicode`
+/// feature), or differences in whitespace and apostrophes.
+pub(crate) fn fold(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .map(|c| match c {
+            '\u{2018}' | '\u{2019}' | '\u{02BC}' => '\'',
+            c => c,
+        })
+        .collect();
+    #[cfg(feature = "unicode")]
+    let text = {
+        use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+        let folded = caseless::default_case_fold_str(&text);
+        let without_accents: String = folded.nfd().filter(|c| !is_combining_mark(*c)).collect();
+        without_accents.nfc().collect::<String>()
+    };
+    #[cfg(not(feature = "unicode"))]
+    let text = text.to_ascii_lowercase();
+    collapse_whitespace(&text)
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str) -> EventSummary {
+        EventSummary {
+            id: "1".into(),
+            name: name.into(),
+            url: "https://www.checkiday.com/1/a".into(),
+            score: None,
+        }
+    }
+
+    #[test]
+    fn collapses_whitespace() {
+        assert_eq!(
+            "National Pizza Day",
+            event("  National \t Pizza\nDay ").normalized_name()
+        );
+    }
+
+    #[test]
+    fn keeps_emoji() {
+        let event = event("🎉  Party Day");
+        assert_eq!("🎉 Party Day", event.normalized_name());
+        assert!(event.matches_name("🎉 party day"));
+        assert!(!event.matches_name("party day"));
+    }
+
+    #[test]
+    fn matches_ignoring_case_and_apostrophes() {
+        let event = event("Mother’s Day");
+        assert!(event.matches_name("mother's  DAY"));
+        assert!(!event.matches_name("Mothers Day"));
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn normalizes_to_nfc() {
+        // "e" followed by a combining acute accent
+        assert_eq!("Café Day", event("Cafe\u{301} Day").normalized_name());
+    }
+
+    #[test]
+    #[cfg(feature = "unicode")]
+    fn matches_ignoring_accents() {
+        let dia = event("Día de los Muertos");
+        assert!(dia.matches_name("dia de los muertos"));
+        assert!(dia.matches_name("DÍA DE LOS MUERTOS"));
+        assert!(!dia.matches_name("dias de los muertos"));
+        assert!(event("Straße Day").matches_name("STRASSE DAY"));
+    }
+
+    #[test]
+    #[cfg(not(feature = "unicode"))]
+    fn matches_only_ascii_case_without_unicode() {
+        let event = event("Día de los Muertos");
+        assert!(event.matches_name("DíA DE LOS MUERTOS"));
+        assert!(!event.matches_name("dia de los muertos"));
+    }
+}
@@ -0,0 +1,115 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+
+use super::RateLimit;
+
+impl RateLimit {
+    /// How long to wait between requests to spread the remaining quota evenly
+    /// over the rest of the month (in UTC), as of `now`.
+    ///
+    /// `None` if no quota remains, or it's unknown (e.g. the default).
+    pub fn suggested_interval(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let remaining = u32::try_from(self.remaining_month)
+            .ok()
+            .filter(|&remaining| remaining > 0)?;
+        Some((next_month(now)? - now).to_std().ok()? / remaining)
+    }
+
+    /// How long until the monthly quota resets, as of `now`: at the start of
+    /// next month, in UTC.
+    ///
+    /// The API doesn't report when the quota resets, so this assumes it's
+    /// at the start of each calendar month. Leap seconds are ignored, like
+    /// everywhere in `chrono`.
+    pub fn time_until_monthly_reset(&self, now: DateTime<Utc>) -> Duration {
+        next_month(now)
+            .and_then(|reset| (reset - now).to_std().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// The start of the month after `now`'s, in UTC.
+fn next_month(now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let (year, month) = match now.month() {
+        12 => (now.year() + 1, 1),
+        month => (now.year(), month + 1),
+    };
+    Some(
+        NaiveDate::from_ymd_opt(year, month, 1)?
+            .and_hms_opt(0, 0, 0)?
+            .and_utc(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(rfc3339: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(rfc3339).unwrap().into()
+    }
+
+    fn remaining(remaining_month: i32) -> RateLimit {
+        RateLimit {
+            limit_month: 1000,
+            remaining_month,
+        }
+    }
+
+    #[test]
+    fn spreads_quota_over_the_month() {
+        // One day left.
+        assert_eq!(
+            Some(Duration::from_secs(864)),
+            remaining(100).suggested_interval(at("2025-05-31T00:00:00Z"))
+        );
+        // 30 days left.
+        assert_eq!(
+            Some(Duration::from_secs(2592)),
+            remaining(1000).suggested_interval(at("2025-04-01T00:00:00Z"))
+        );
+        // Across the year boundary, with sub-second precision.
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            remaining(2).suggested_interval(at("2025-12-31T23:59:59Z"))
+        );
+    }
+
+    #[test]
+    fn counts_down_to_next_month() {
+        let limit = remaining(10);
+        assert_eq!(
+            Duration::from_secs(11 * 86400 + 4 * 3600),
+            limit.time_until_monthly_reset(at("2025-05-20T20:00:00Z"))
+        );
+        // February of a leap year.
+        assert_eq!(
+            Duration::from_secs(29 * 86400),
+            limit.time_until_monthly_reset(at("2024-02-01T00:00:00Z"))
+        );
+        // December rolls over to January.
+        assert_eq!(
+            Duration::from_millis(1),
+            limit.time_until_monthly_reset(at("2025-12-31T23:59:59.999Z"))
+        );
+        // Exactly at the reset, the next one is a month away.
+        assert_eq!(
+            Duration::from_secs(31 * 86400),
+            limit.time_until_monthly_reset(at("2026-01-01T00:00:00Z"))
+        );
+        // Regardless of the remaining quota.
+        assert_eq!(
+            Duration::from_secs(86400),
+            RateLimit::default().time_until_monthly_reset(at("2025-06-30T00:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn has_no_interval_without_quota() {
+        let now = at("2025-05-15T12:00:00Z");
+        assert_eq!(None, remaining(0).suggested_interval(now));
+        assert_eq!(None, remaining(-5).suggested_interval(now));
+        assert_eq!(None, RateLimit::default().suggested_interval(now));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::Error;
+
+/// The oldest year accepted in an [`OccurrenceRange`], the first full year of
+/// the Gregorian calendar.
+pub const MIN_YEAR: i32 = 1583;
+/// The latest year accepted in an [`OccurrenceRange`].
+pub const MAX_YEAR: i32 = 9999;
+
+/// The years to get an Event's Occurrences for, used by
+/// [`HolidayEventApi::get_event_info_ranged`](crate::HolidayEventApi::get_event_info_ranged).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OccurrenceRange {
+    /// From `start` to `end`, inclusive
+    Years {
+        /// The first year
+        start: i32,
+        /// The last year
+        end: i32,
+    },
+    /// From `back` years before the current year to `forward` years after it
+    RelativeToNow {
+        /// How many years before the current year to start at
+        back: u8,
+        /// How many years after the current year to end at
+        forward: u8,
+    },
+}
+
+impl OccurrenceRange {
+    /// The range's first and last year, given the current year.
+    ///
+    /// Fails unless both years are between [`MIN_YEAR`] and [`MAX_YEAR`] and
+    /// the first isn't after the last.
+    pub fn resolve(self, current_year: i32) -> Result<(i32, i32), Error> {
+        let (start, end) = match self {
+            OccurrenceRange::Years { start, end } => (start, end),
+            OccurrenceRange::RelativeToNow { back, forward } => {
+                (current_year - back as i32, current_year + forward as i32)
+            }
+        };
+        if start > end || start < MIN_YEAR || end > MAX_YEAR {
+            return Err(Error::InvalidRange { start, end });
+        }
+        Ok((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_years() {
+        let range = OccurrenceRange::Years {
+            start: 2020,
+            end: 2030,
+        };
+        assert_eq!((2020, 2030), range.resolve(1999).unwrap());
+    }
+
+    #[test]
+    fn resolves_relative_to_now() {
+        let range = OccurrenceRange::RelativeToNow {
+            back: 2,
+            forward: 3,
+        };
+        assert_eq!((2023, 2028), range.resolve(2025).unwrap());
+        assert_eq!((2024, 2029), range.resolve(2026).unwrap());
+    }
+
+    #[test]
+    fn accepts_bounds() {
+        let range = OccurrenceRange::Years {
+            start: MIN_YEAR,
+            end: MAX_YEAR,
+        };
+        assert_eq!((MIN_YEAR, MAX_YEAR), range.resolve(2025).unwrap());
+    }
+
+    #[test]
+    fn rejects_invalid_ranges() {
+        let ranges = [
+            OccurrenceRange::Years {
+                start: 2030,
+                end: 2020,
+            },
+            OccurrenceRange::Years {
+                start: MIN_YEAR - 1,
+                end: 2020,
+            },
+            OccurrenceRange::Years {
+                start: 2020,
+                end: MAX_YEAR + 1,
+            },
+            OccurrenceRange::RelativeToNow {
+                back: 0,
+                forward: 255,
+            },
+        ];
+        for range in ranges {
+            assert!(
+                matches!(range.resolve(9800), Err(Error::InvalidRange { .. })),
+                "{range:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn describes_invalid_ranges() {
+        let range = OccurrenceRange::Years {
+            start: 2030,
+            end: 2020,
+        };
+        assert_eq!(
+            "Invalid occurrence range: 2030 to 2020.",
+            range.resolve(2025).unwrap_err().to_string()
+        );
+    }
+}
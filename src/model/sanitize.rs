@@ -0,0 +1,165 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use super::RichText;
+
+/// The HTML tags, attributes, and URL schemes kept by
+/// [`RichText::sanitized_html_with`]. Everything else is stripped.
+///
+/// The default keeps `p`, `a` (with `href` only), `em`, `strong`, `ul`, and
+/// `li`, and links to `http`, `https`, and `mailto` URLs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SanitizePolicy {
+    /// The tags to keep. The contents of other tags are kept, except for
+    /// `script` and `style`, whose contents are removed too.
+    pub tags: BTreeSet<String>,
+    /// The attributes to keep, per tag
+    pub attributes: BTreeMap<String, BTreeSet<String>>,
+    /// The URL schemes links may use. Relative URLs are removed.
+    pub url_schemes: BTreeSet<String>,
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        let set = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+        Self {
+            tags: set(&["p", "a", "em", "strong", "ul", "li"]),
+            attributes: BTreeMap::from([("a".into(), set(&["href"]))]),
+            url_schemes: set(&["http", "https", "mailto"]),
+        }
+    }
+}
+
+impl RichText {
+    /// The HTML, keeping only the tags and attributes of the default
+    /// [`SanitizePolicy`], e.g. before rendering it into a web page.
+    pub fn sanitized_html(&self) -> Option<String> {
+        self.sanitized_html_with(&SanitizePolicy::default())
+    }
+
+    /// The HTML, keeping only the tags and attributes allowed by `policy`.
+    pub fn sanitized_html_with(&self, policy: &SanitizePolicy) -> Option<String> {
+        let html = self.html.as_ref()?;
+        let tags: HashSet<&str> = policy.tags.iter().map(String::as_str).collect();
+        let attributes: HashMap<&str, HashSet<&str>> = policy
+            .attributes
+            .iter()
+            .map(|(tag, attributes)| {
+                let attributes = attributes.iter().map(String::as_str).collect();
+                (tag.as_str(), attributes)
+            })
+            .collect();
+        // ammonia panics if a tag is both kept and removed with its contents,
+        // or if links get a `rel` both from the input and added.
+        let removed_with_contents = ["script", "style"]
+            .into_iter()
+            .filter(|tag| !tags.contains(tag) && !attributes.contains_key(tag))
+            .collect();
+        let keeps_rel = attributes.get("a").is_some_and(|a| a.contains("rel"));
+
+        let cleaned = ammonia::Builder::empty()
+            .tags(tags)
+            .clean_content_tags(removed_with_contents)
+            .tag_attributes(attributes)
+            .generic_attributes(HashSet::new())
+            .url_schemes(policy.url_schemes.iter().map(String::as_str).collect())
+            .url_relative(ammonia::UrlRelative::Deny)
+            .link_rel((!keeps_rel).then_some("noopener noreferrer"))
+            .clean(html)
+            .to_string();
+        Some(cleaned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::fixtures;
+
+    fn html(html: &str) -> RichText {
+        RichText {
+            text: None,
+            html: Some(html.into()),
+            markdown: None,
+        }
+    }
+
+    #[test]
+    fn keeps_fixture_html() {
+        let event = fixtures::event_info();
+
+        assert_eq!(
+            Some(
+                "<p>International Cat Day <a href=\"https://www.google.com\" rel=\"noopener noreferrer\">celebrates</a> love for cats...</p>"
+                    .into()
+            ),
+            event.description.unwrap().sanitized_html()
+        );
+    }
+
+    #[test]
+    fn removes_scripts() {
+        assert_eq!(
+            Some("<p>Hi</p>".into()),
+            html("<p>Hi<script>alert(1)</script></p>").sanitized_html()
+        );
+    }
+
+    #[test]
+    fn removes_event_handlers() {
+        assert_eq!(
+            Some("<p>Hi</p>".into()),
+            html("<p onclick=\"alert(1)\">Hi</p>").sanitized_html()
+        );
+    }
+
+    #[test]
+    fn removes_javascript_urls() {
+        assert_eq!(
+            Some("<a rel=\"noopener noreferrer\">Hi</a>".into()),
+            html("<a href=\"javascript:alert(1)\">Hi</a>").sanitized_html()
+        );
+    }
+
+    #[test]
+    fn removes_other_tags() {
+        assert_eq!(
+            Some("<ul><li>One <em>two</em></li></ul>Three".into()),
+            html("<ul><li>One <em>two</em></li></ul><div><img src=x>Three</div>").sanitized_html()
+        );
+    }
+
+    #[test]
+    fn handles_malformed_html() {
+        assert_eq!(
+            Some("<p><strong>Hi</strong></p><strong>&lt;</strong>".into()),
+            html("<p><strong>Hi</p></a><").sanitized_html()
+        );
+    }
+
+    #[test]
+    fn returns_none_without_html() {
+        let text = RichText {
+            text: Some("Hi".into()),
+            html: None,
+            markdown: None,
+        };
+        assert_eq!(None, text.sanitized_html());
+    }
+
+    #[test]
+    fn follows_custom_policy() {
+        let mut policy = SanitizePolicy::default();
+        policy.tags.insert("script".into());
+        policy
+            .attributes
+            .insert("a".into(), ["href", "rel"].map(String::from).into());
+
+        assert_eq!(
+            Some(
+                "<a href=\"https://example.com\" rel=\"nofollow\">Hi</a><script>1</script>".into()
+            ),
+            html("<a href=\"https://example.com\" rel=\"nofollow\">Hi</a><script>1</script>")
+                .sanitized_html_with(&policy)
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::Weekday;
+
+use super::Pattern;
+
+/// A structured form of [`Pattern::observed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Schedule {
+    /// Observed on the same date every year, e.g. "annually on August 8th"
+    AnnuallyOn {
+        /// The month, from 1 (January) to 12 (December)
+        month: u32,
+        /// The day of the month
+        day: u32,
+    },
+    /// Observed on the nth weekday of a month every year, e.g. "annually on the second Sunday of
+    /// May"
+    NthWeekdayOf {
+        /// Which weekday of the month, starting at 1. Negative values count from the end of the
+        /// month (-1 is the last).
+        nth: i32,
+        /// The day of the week
+        weekday: Weekday,
+        /// The month, from 1 (January) to 12 (December)
+        month: u32,
+    },
+}
+
+impl Pattern {
+    /// Parses [`Pattern::observed`] into a [`Schedule`].
+    ///
+    /// Returns `None` for descriptions that aren't recognized.
+    pub fn schedule(&self) -> Option<Schedule> {
+        parse_schedule(&self.observed)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const DAYS_IN_MONTH: [u32; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+fn parse_schedule(observed: &str) -> Option<Schedule> {
+    let observed = observed.trim().to_lowercase();
+    let rest = observed.strip_prefix("annually on ")?;
+    let rest = rest.strip_prefix("the ").unwrap_or(rest);
+    let words: Vec<&str> = rest.split_whitespace().collect();
+
+    match words.as_slice() {
+        [month, day] => {
+            let month = parse_month(month)?;
+            let day = parse_day(day)?;
+            (day <= DAYS_IN_MONTH[month as usize - 1])
+                .then_some(Schedule::AnnuallyOn { month, day })
+        }
+        [nth, weekday, "of" | "in", month] => Some(Schedule::NthWeekdayOf {
+            nth: parse_nth(nth)?,
+            weekday: weekday.parse().ok()?,
+            month: parse_month(month)?,
+        }),
+        _ => None,
+    }
+}
+
+fn parse_month(month: &str) -> Option<u32> {
+    MONTHS
+        .iter()
+        .position(|m| *m == month)
+        .map(|index| index as u32 + 1)
+}
+
+fn parse_day(day: &str) -> Option<u32> {
+    let digits = day
+        .strip_suffix("st")
+        .or_else(|| day.strip_suffix("nd"))
+        .or_else(|| day.strip_suffix("rd"))
+        .or_else(|| day.strip_suffix("th"))
+        .unwrap_or(day);
+    digits.parse().ok().filter(|day| *day >= 1)
+}
+
+fn parse_nth(nth: &str) -> Option<i32> {
+    match nth {
+        "first" | "1st" => Some(1),
+        "second" | "2nd" => Some(2),
+        "third" | "3rd" => Some(3),
+        "fourth" | "4th" => Some(4),
+        "fifth" | "5th" => Some(5),
+        "last" => Some(-1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_fixed_dates() {
+        assert_eq!(
+            Some(Schedule::AnnuallyOn { month: 8, day: 8 }),
+            parse_schedule("annually on August 8th")
+        );
+        assert_eq!(
+            Some(Schedule::AnnuallyOn { month: 2, day: 29 }),
+            parse_schedule("Annually on February 29")
+        );
+    }
+
+    #[test]
+    fn parses_nth_weekdays() {
+        assert_eq!(
+            Some(Schedule::NthWeekdayOf {
+                nth: 2,
+                weekday: Weekday::Sun,
+                month: 5
+            }),
+            parse_schedule("annually on the second Sunday of May")
+        );
+        assert_eq!(
+            Some(Schedule::NthWeekdayOf {
+                nth: -1,
+                weekday: Weekday::Mon,
+                month: 5
+            }),
+            parse_schedule("annually on the last Monday in May")
+        );
+        assert_eq!(
+            Some(Schedule::NthWeekdayOf {
+                nth: 4,
+                weekday: Weekday::Thu,
+                month: 11
+            }),
+            parse_schedule("annually on the 4th Thursday of November")
+        );
+    }
+
+    #[test]
+    fn rejects_unrecognized_descriptions() {
+        for observed in [
+            "",
+            "every four years",
+            "annually on August",
+            "annually on Smarch 8th",
+            "annually on February 30th",
+            "annually on August 0th",
+            "annually on the sixth Sunday of May",
+            "annually on the second Funday of May",
+            "annually on the day after Thanksgiving",
+        ] {
+            assert_eq!(None, parse_schedule(observed), "{observed:?}");
+        }
+    }
+
+    #[test]
+    fn parses_pattern_observed() {
+        let pattern = Pattern {
+            first_year: Some(2002),
+            last_year: None,
+            observed: "annually on August 8th".into(),
+            observed_html: "annually on <a href=\"https://www.checkiday.com/8/8\">August 8th</a>"
+                .into(),
+            observed_markdown: "annually on [August 8th](https://www.checkiday.com/8/8)".into(),
+            length: 1,
+        };
+        assert_eq!(
+            Some(Schedule::AnnuallyOn { month: 8, day: 8 }),
+            pattern.schedule()
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Grouping search results with similar names.
+
+use std::collections::HashSet;
+
+use super::{names, EventSummary, SearchResponse};
+
+impl SearchResponse {
+    /// The found Events, grouped with those whose names are similar, e.g.
+    /// "National Pizza Day" and "Pizza Day".
+    ///
+    /// Names are compared as sets of words, ignoring case (see
+    /// [`EventSummary::matches_name`]) and punctuation: their similarity is
+    /// the share of words they have in common (Jaccard index). Events are
+    /// grouped when it's at least `threshold`, clamped to 0.0–1.0, and with
+    /// the Events they're grouped with in turn. Every Event is in exactly one
+    /// group, alone if need be. Groups and their Events keep the order
+    /// returned by the API.
+    pub fn group_similar(&self, threshold: f32) -> Vec<Vec<&EventSummary>> {
+        let threshold = threshold.clamp(0.0, 1.0);
+        let words: Vec<HashSet<String>> = self.events.iter().map(|e| words(&e.name)).collect();
+        // Each Event's group, as the index of the group's first Event.
+        let mut group: Vec<usize> = (0..self.events.len()).collect();
+        for i in 0..self.events.len() {
+            for j in 0..i {
+                if similarity(&words[i], &words[j]) >= threshold {
+                    let (from, to) = (group[i].max(group[j]), group[i].min(group[j]));
+                    for g in group.iter_mut().filter(|g| **g == from) {
+                        *g = to;
+                    }
+                }
+            }
+        }
+        let mut groups: Vec<Vec<&EventSummary>> = vec![];
+        let mut position = vec![0; self.events.len()];
+        for (i, event) in self.events.iter().enumerate() {
+            if group[i] == i {
+                position[i] = groups.len();
+                groups.push(vec![event]);
+            } else {
+                groups[position[group[i]]].push(event);
+            }
+        }
+        groups
+    }
+
+    /// One Event of each [`group_similar`](Self::group_similar) group: the
+    /// one with the longest name, or the first of those.
+    pub fn dedup_similar(&self, threshold: f32) -> Vec<&EventSummary> {
+        self.group_similar(threshold)
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .rev()
+                    .max_by_key(|event| event.name.chars().count())
+                    .unwrap()
+            })
+            .collect()
+    }
+}
+
+/// The distinct words of `name`, folded.
+fn words(name: &str) -> HashSet<String> {
+    names::fold(name)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(Into::into)
+        .collect()
+}
+
+/// The Jaccard index of two sets of words, 0.0 if both are empty.
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::RateLimit;
+
+    fn search(names: &[&str]) -> SearchResponse {
+        SearchResponse {
+            query: "day".into(),
+            adult: false,
+            events: names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| EventSummary {
+                    id: i.to_string().as_str().into(),
+                    name: name.to_string(),
+                    url: format!("https://www.checkiday.com/{i}/a").as_str().into(),
+                    score: None,
+                })
+                .collect(),
+            rate_limit: RateLimit::default(),
+        }
+    }
+
+    fn names(groups: Vec<Vec<&EventSummary>>) -> Vec<Vec<&str>> {
+        groups
+            .into_iter()
+            .map(|group| group.into_iter().map(|e| e.name.as_str()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn groups_exact_duplicates() {
+        let search = search(&["Pizza Day", "Cat Day", "pizza  day"]);
+        assert_eq!(
+            vec![vec!["Pizza Day", "pizza  day"], vec!["Cat Day"]],
+            names(search.group_similar(1.0))
+        );
+    }
+
+    #[test]
+    fn groups_names_within_names() {
+        let search = search(&["National Pizza Day", "Pizza Day", "National Cat Day"]);
+        assert_eq!(
+            vec![
+                vec!["National Pizza Day", "Pizza Day"],
+                vec!["National Cat Day"]
+            ],
+            names(search.group_similar(0.6))
+        );
+        // "National Pizza Day" and "National Cat Day" share 2 of 4 words.
+        assert_eq!(1, search.group_similar(0.5).len());
+    }
+
+    #[test]
+    fn keeps_unrelated_names_alone() {
+        let search = search(&["Pizza Day", "Talk Like a Pirate Day", "Pi Day"]);
+        assert_eq!(
+            vec![
+                vec!["Pizza Day"],
+                vec!["Talk Like a Pirate Day"],
+                vec!["Pi Day"]
+            ],
+            names(search.group_similar(0.5))
+        );
+    }
+
+    #[test]
+    fn groups_transitively() {
+        let search = search(&["A B", "C D", "B C"]);
+        assert_eq!(
+            vec![vec!["A B", "C D", "B C"]],
+            names(search.group_similar(0.3))
+        );
+    }
+
+    #[test]
+    fn clamps_threshold() {
+        let search = search(&["Pizza Day", "Cat Day", "!!!"]);
+        assert_eq!(1, search.group_similar(-1.0).len());
+        assert_eq!(3, search.group_similar(2.0).len());
+    }
+
+    #[test]
+    fn keeps_longest_names() {
+        let search = search(&["Pizza Day", "National Pizza Day", "Cat Day", "cat day"]);
+        let names: Vec<&str> = search
+            .dedup_similar(0.6)
+            .into_iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(vec!["National Pizza Day", "Cat Day"], names);
+    }
+}
@@ -0,0 +1,127 @@
+//! Summary statistics of an Event's Occurrences.
+
+use std::collections::BTreeSet;
+
+use chrono::Datelike;
+
+use super::EventInfo;
+
+/// Statistics of an Event's Occurrences, see [`EventInfo::occurrence_stats`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OccurrenceStats {
+    /// The number of Occurrences
+    pub count: usize,
+    /// The number of distinct years with an Occurrence
+    pub years_covered: usize,
+    /// The shortest Occurrence, in days
+    pub min_length: i32,
+    /// The longest Occurrence, in days
+    pub max_length: i32,
+    /// The mean length of the Occurrences, in days
+    pub mean_length: f64,
+    /// The years without an Occurrence between the first and last years with
+    /// one, in ascending order
+    pub gaps: Vec<i32>,
+}
+
+impl EventInfo {
+    /// Statistics of the Occurrences, or `None` without any.
+    ///
+    /// Timestamps count towards their year in UTC. Occurrences whose date
+    /// can't be parsed count towards lengths only. The requested range isn't
+    /// part of the response, so `gaps` only covers the years from the first
+    /// Occurrence to the last.
+    pub fn occurrence_stats(&self) -> Option<OccurrenceStats> {
+        let occurrences = self.occurrences.as_deref().filter(|o| !o.is_empty())?;
+        let lengths = occurrences.iter().map(|occurrence| occurrence.length);
+        let years: BTreeSet<i32> = occurrences
+            .iter()
+            .filter_map(|occurrence| occurrence.date.parse_flexible().date())
+            .map(|date| date.year())
+            .collect();
+        let gaps = match (years.first(), years.last()) {
+            (Some(&first), Some(&last)) => (first..=last)
+                .filter(|year| !years.contains(year))
+                .collect(),
+            _ => vec![],
+        };
+        Some(OccurrenceStats {
+            count: occurrences.len(),
+            years_covered: years.len(),
+            min_length: lengths.clone().min()?,
+            max_length: lengths.clone().max()?,
+            mean_length: lengths.map(f64::from).sum::<f64>() / occurrences.len() as f64,
+            gaps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{fixtures::event_info, DateOrTimestamp, Occurrence};
+
+    fn occurrence(date: &str, length: i32) -> Occurrence {
+        Occurrence {
+            date: DateOrTimestamp::Date(date.into()),
+            length,
+        }
+    }
+
+    #[test]
+    fn summarizes_fixture() {
+        let stats = event_info().occurrence_stats().unwrap();
+        assert_eq!(7, stats.count);
+        // 2020 to 2024, with 2024 twice, and 1969 (a negative timestamp).
+        assert_eq!(6, stats.years_covered);
+        assert_eq!(1, stats.min_length);
+        assert_eq!(7, stats.max_length);
+        assert_eq!(13.0 / 7.0, stats.mean_length);
+        assert_eq!((1970..2020).collect::<Vec<_>>(), stats.gaps);
+    }
+
+    #[test]
+    fn finds_gaps_between_years() {
+        let event = EventInfo {
+            occurrences: Some(vec![
+                occurrence("03/01/2020", 2),
+                occurrence("03/05/2020", 4),
+                occurrence("2023-03-01", 3),
+                occurrence("someday", 9),
+                occurrence("03/01/2025", 2),
+            ]),
+            ..event_info()
+        };
+        assert_eq!(
+            Some(OccurrenceStats {
+                count: 5,
+                years_covered: 3,
+                min_length: 2,
+                max_length: 9,
+                mean_length: 4.0,
+                gaps: vec![2021, 2022, 2024],
+            }),
+            event.occurrence_stats()
+        );
+    }
+
+    #[test]
+    fn skips_gaps_without_dates() {
+        let event = EventInfo {
+            occurrences: Some(vec![occurrence("someday", 1)]),
+            ..event_info()
+        };
+        let stats = event.occurrence_stats().unwrap();
+        assert_eq!(0, stats.years_covered);
+        assert!(stats.gaps.is_empty());
+    }
+
+    #[test]
+    fn needs_occurrences() {
+        let mut event = event_info();
+        event.occurrences = Some(vec![]);
+        assert_eq!(None, event.occurrence_stats());
+        event.occurrences = None;
+        assert_eq!(None, event.occurrence_stats());
+    }
+}
@@ -0,0 +1,102 @@
+use super::GetEventsResponse;
+
+impl GetEventsResponse {
+    /// The Events' names and URLs as an ASCII table, e.g. for command-line
+    /// output.
+    ///
+    /// Events are listed once each, as in
+    /// [`unique_events`](Self::unique_events). Columns are aligned by
+    /// character count, so wide characters (e.g. emoji) may misalign them.
+    pub fn to_table(&self) -> String {
+        let rows: Vec<[&str; 2]> = self
+            .unique_events()
+            .into_iter()
+            .map(|event| [event.name.as_str(), event.url.as_str()])
+            .collect();
+        let mut widths = HEADER.map(|cell| cell.chars().count());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let mut table = String::new();
+        push_border(&mut table, &widths);
+        push_row(&mut table, &widths, &HEADER);
+        push_border(&mut table, &widths);
+        for row in &rows {
+            push_row(&mut table, &widths, row);
+        }
+        push_border(&mut table, &widths);
+        table
+    }
+}
+
+const HEADER: [&str; 2] = ["Name", "URL"];
+
+fn push_border(table: &mut String, widths: &[usize; 2]) {
+    for width in widths {
+        table.push('+');
+        table.push_str(&"-".repeat(width + 2));
+    }
+    table.push_str("+\n");
+}
+
+fn push_row(table: &mut String, widths: &[usize; 2], row: &[&str; 2]) {
+    for (width, cell) in widths.iter().zip(row) {
+        table.push_str(&format!("| {:<width$} ", cell, width = width));
+    }
+    table.push_str("|\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events() -> GetEventsResponse {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn lists_each_event() {
+        let events = events();
+        let table = events.to_table();
+        for event in events.unique_events() {
+            assert!(table.contains(&event.name), "{}", event.name);
+            assert!(table.contains(event.url.as_str()), "{}", event.name);
+        }
+        // Header, 5 Events, and 3 borders.
+        assert_eq!(9, table.lines().count());
+    }
+
+    #[test]
+    fn aligns_columns() {
+        let mut events = events();
+        events.events.truncate(1);
+        events.events[0].name = "Día".into();
+        events.multiday_starting.clear();
+        events.multiday_ongoing.clear();
+        let url = events.events[0].url.as_str().to_string();
+        let border = format!("+------+-{}-+", "-".repeat(url.len()));
+        assert_eq!(
+            format!(
+                "{border}\n| Name | URL{} |\n{border}\n| Día  | {url} |\n{border}\n",
+                " ".repeat(url.len() - 3)
+            ),
+            events.to_table()
+        );
+    }
+
+    #[test]
+    fn has_header_without_events() {
+        let mut events = events();
+        events.events.clear();
+        events.multiday_starting.clear();
+        events.multiday_ongoing.clear();
+        assert_eq!(
+            "+------+-----+\n| Name | URL |\n+------+-----+\n+------+-----+\n",
+            events.to_table()
+        );
+    }
+}
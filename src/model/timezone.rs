@@ -0,0 +1,106 @@
+use std::fmt;
+
+use chrono_tz::Tz;
+
+/// An IANA timezone for get_events.
+///
+/// Names that chrono-tz doesn't know (e.g. added to the API's database
+/// since) are kept as [`Timezone::Raw`] and sent as-is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Timezone {
+    /// A timezone known to chrono-tz
+    Iana(Tz),
+    /// The original name, which chrono-tz doesn't know
+    Raw(String),
+}
+
+impl Timezone {
+    /// The timezone's name, as sent to the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Timezone::Iana(tz) => tz.name(),
+            Timezone::Raw(name) => name,
+        }
+    }
+
+    /// The chrono-tz timezone, if known.
+    pub fn tz(&self) -> Option<Tz> {
+        match self {
+            Timezone::Iana(tz) => Some(*tz),
+            Timezone::Raw(_) => None,
+        }
+    }
+}
+
+impl From<Tz> for Timezone {
+    fn from(tz: Tz) -> Self {
+        Timezone::Iana(tz)
+    }
+}
+
+impl From<&str> for Timezone {
+    fn from(name: &str) -> Self {
+        match name.parse() {
+            Ok(tz) => Timezone::Iana(tz),
+            Err(_) => Timezone::Raw(name.into()),
+        }
+    }
+}
+
+impl From<String> for Timezone {
+    fn from(name: String) -> Self {
+        match name.parse() {
+            Ok(tz) => Timezone::Iana(tz),
+            Err(_) => Timezone::Raw(name),
+        }
+    }
+}
+
+impl fmt::Display for Timezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_names() {
+        assert_eq!(
+            Timezone::Iana(Tz::America__New_York),
+            "America/New_York".into()
+        );
+        assert_eq!(Timezone::Iana(Tz::UTC), Timezone::from(String::from("UTC")));
+        assert_eq!(
+            Timezone::Iana(Tz::Europe__Berlin),
+            Tz::Europe__Berlin.into()
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_names() {
+        assert_eq!(
+            Timezone::Raw("America/Gotham".into()),
+            "America/Gotham".into()
+        );
+        assert_eq!(
+            Timezone::Raw("Mars/Olympus".into()),
+            Timezone::from(String::from("Mars/Olympus"))
+        );
+    }
+
+    #[test]
+    fn names_both_variants() {
+        let iana = Timezone::from(Tz::Asia__Tokyo);
+        assert_eq!("Asia/Tokyo", iana.as_str());
+        assert_eq!("Asia/Tokyo", iana.to_string());
+        assert_eq!(Some(Tz::Asia__Tokyo), iana.tz());
+
+        let raw = Timezone::from("America/Gotham");
+        assert_eq!("America/Gotham", raw.as_str());
+        assert_eq!("America/Gotham", raw.to_string());
+        assert_eq!(None, raw.tz());
+    }
+}
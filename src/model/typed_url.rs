@@ -0,0 +1,165 @@
+use std::fmt;
+
+use reqwest::Url;
+use serde::Deserialize;
+
+/// A URL returned by the API.
+///
+/// URLs that fail to parse are kept as [`TypedUrl::Malformed`] rather than
+/// failing the whole response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypedUrl {
+    /// A well-formed URL
+    Valid(Url),
+    /// The original value, which is not a valid URL
+    Malformed(String),
+}
+
+impl TypedUrl {
+    /// The URL as a string. Valid URLs are in their normalized form.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TypedUrl::Valid(url) => url.as_str(),
+            TypedUrl::Malformed(url) => url,
+        }
+    }
+
+    /// The parsed URL, if valid.
+    pub fn url(&self) -> Option<&Url> {
+        match self {
+            TypedUrl::Valid(url) => Some(url),
+            TypedUrl::Malformed(_) => None,
+        }
+    }
+}
+
+impl From<&str> for TypedUrl {
+    fn from(url: &str) -> Self {
+        match Url::parse(url) {
+            Ok(parsed) => TypedUrl::Valid(parsed),
+            Err(_) => TypedUrl::Malformed(url.into()),
+        }
+    }
+}
+
+impl From<String> for TypedUrl {
+    fn from(url: String) -> Self {
+        match Url::parse(&url) {
+            Ok(parsed) => TypedUrl::Valid(parsed),
+            Err(_) => TypedUrl::Malformed(url),
+        }
+    }
+}
+
+impl fmt::Display for TypedUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedUrl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(TypedUrl::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{GetEventInfoResponse, GetEventsResponse};
+
+    fn raw_urls(json: &str) -> Vec<String> {
+        fn collect(value: &serde_json::Value, urls: &mut Vec<String>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    for (key, value) in map {
+                        match value {
+                            serde_json::Value::String(s)
+                                if ["url", "small", "medium", "large"].contains(&key.as_str()) =>
+                            {
+                                urls.push(s.clone())
+                            }
+                            _ => collect(value, urls),
+                        }
+                    }
+                }
+                serde_json::Value::Array(values) => {
+                    values.iter().for_each(|value| collect(value, urls))
+                }
+                _ => {}
+            }
+        }
+        let mut urls = vec![];
+        collect(&serde_json::from_str(json).unwrap(), &mut urls);
+        urls
+    }
+
+    #[test]
+    fn matches_fixture_strings() {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        let response: GetEventsResponse = serde_json::from_str(&json).unwrap();
+        let mut urls: Vec<String> = response
+            .events
+            .iter()
+            .chain(&response.multiday_starting)
+            .chain(&response.multiday_ongoing)
+            .map(|e| {
+                assert!(e.url.url().is_some());
+                e.url.as_str().to_string()
+            })
+            .collect();
+        let mut expected = raw_urls(&json);
+        urls.sort();
+        expected.sort();
+        assert_eq!(expected, urls);
+
+        let json = std::fs::read_to_string("testdata/getEventInfo-default.json").unwrap();
+        let event = serde_json::from_str::<GetEventInfoResponse>(&json)
+            .unwrap()
+            .event;
+        let image = event.image.as_ref().unwrap();
+        let founder = &event.founders.as_ref().unwrap()[0];
+        let mut urls = vec![
+            event.url.as_str(),
+            image.small.as_str(),
+            image.medium.as_str(),
+            image.large.as_str(),
+            founder.url.as_ref().unwrap().as_str(),
+        ];
+        let mut expected = raw_urls(&json);
+        urls.sort();
+        expected.sort();
+        assert_eq!(expected, urls);
+    }
+
+    #[test]
+    fn keeps_malformed_urls() {
+        let json = r#"{
+            "query": "zucchini",
+            "adult": false,
+            "events": [
+                {"id": "a", "name": "A", "url": "not a url"},
+                {"id": "b", "name": "B", "url": "https://www.checkiday.com/b"}
+            ]
+        }"#;
+        let response: crate::model::SearchResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            TypedUrl::Malformed("not a url".into()),
+            response.events[0].url
+        );
+        assert_eq!("not a url", response.events[0].url.as_str());
+        assert_eq!(None, response.events[0].url.url());
+        assert_eq!(
+            Some("www.checkiday.com"),
+            response.events[1].url.url().unwrap().host_str()
+        );
+        assert_eq!(
+            "https://www.checkiday.com/b",
+            response.events[1].url.to_string()
+        );
+    }
+}
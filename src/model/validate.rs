@@ -0,0 +1,323 @@
+//! Soft checks of responses for signs of upstream bugs.
+
+use std::{collections::HashSet, fmt, sync::Arc};
+
+use reqwest::Url;
+
+use super::{EventInfo, EventSummary, GetEventsResponse, SearchResponse};
+use crate::Endpoint;
+
+/// A sign of an upstream bug in a response that still parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationWarning {
+    /// An Event id is listed more than once, e.g. in several buckets
+    DuplicateId {
+        /// The Event id
+        id: String,
+    },
+    /// An Event has an empty id
+    EmptyId {
+        /// The Event name
+        name: String,
+    },
+    /// An Event has an empty name
+    EmptyName {
+        /// The Event id
+        id: String,
+    },
+    /// An Event's URL doesn't start with its id
+    IdUrlMismatch {
+        /// The Event id
+        id: String,
+        /// The Event URL
+        url: String,
+    },
+    /// An Occurrence is 0 days long or less
+    NonPositiveLength {
+        /// The Occurrence's index in `occurrences`
+        index: usize,
+        /// The Occurrence's length
+        length: i32,
+    },
+    /// A first year is after the last year, in a Pattern or Alternate Name
+    YearsReversed {
+        /// The first year
+        first_year: i32,
+        /// The last year
+        last_year: i32,
+    },
+}
+
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId { id } => write!(f, "Duplicate Event id: {}", id),
+            Self::EmptyId { name } => write!(f, "Empty id for Event: {}", name),
+            Self::EmptyName { id } => write!(f, "Empty name for Event: {}", id),
+            Self::IdUrlMismatch { id, url } => write!(f, "URL of Event {} is {}", id, url),
+            Self::NonPositiveLength { index, length } => {
+                write!(f, "Occurrence {} has length {}", index, length)
+            }
+            Self::YearsReversed {
+                first_year,
+                last_year,
+            } => write!(
+                f,
+                "First year {} is after last year {}",
+                first_year, last_year
+            ),
+        }
+    }
+}
+
+impl GetEventsResponse {
+    /// Signs of upstream bugs in the response, which parsed nonetheless.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        summaries(self.buckets().map(|(_, event)| event))
+    }
+}
+
+impl SearchResponse {
+    /// Signs of upstream bugs in the response, which parsed nonetheless.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        summaries(self.events.iter())
+    }
+}
+
+impl EventInfo {
+    /// Signs of upstream bugs in the Event, which parsed nonetheless.
+    pub fn validate(&self) -> Vec<ValidationWarning> {
+        let mut warnings = vec![];
+        event(&self.id, &self.name, self.url.as_str(), &mut warnings);
+        let years = self
+            .alternate_names
+            .iter()
+            .map(|name| (name.first_year, name.last_year))
+            .chain(
+                self.patterns
+                    .iter()
+                    .flatten()
+                    .map(|pattern| (pattern.first_year, pattern.last_year)),
+            );
+        for (first_year, last_year) in years {
+            if let (Some(first_year), Some(last_year)) = (first_year, last_year) {
+                if first_year > last_year {
+                    warnings.push(ValidationWarning::YearsReversed {
+                        first_year,
+                        last_year,
+                    });
+                }
+            }
+        }
+        for (index, occurrence) in self.occurrences.iter().flatten().enumerate() {
+            if occurrence.length <= 0 {
+                warnings.push(ValidationWarning::NonPositiveLength {
+                    index,
+                    length: occurrence.length,
+                });
+            }
+        }
+        warnings
+    }
+}
+
+fn summaries<'a>(events: impl Iterator<Item = &'a EventSummary>) -> Vec<ValidationWarning> {
+    let mut warnings = vec![];
+    let mut seen = HashSet::new();
+    for summary in events {
+        if !summary.id.is_empty() && !seen.insert(summary.id.as_str()) {
+            warnings.push(ValidationWarning::DuplicateId {
+                id: summary.id.clone(),
+            });
+        }
+        event(
+            &summary.id,
+            &summary.name,
+            summary.url.as_str(),
+            &mut warnings,
+        );
+    }
+    warnings
+}
+
+fn event(id: &str, name: &str, url: &str, warnings: &mut Vec<ValidationWarning>) {
+    if id.is_empty() {
+        warnings.push(ValidationWarning::EmptyId { name: name.into() });
+    }
+    if name.trim().is_empty() {
+        warnings.push(ValidationWarning::EmptyName { id: id.into() });
+    }
+    // Event URLs look like https://www.checkiday.com/<id>/<slug>.
+    let url_id = Url::parse(url)
+        .ok()
+        .and_then(|url| Some(url.path_segments()?.next()?.to_string()));
+    if !id.is_empty() && url_id.as_deref() != Some(id) {
+        warnings.push(ValidationWarning::IdUrlMismatch {
+            id: id.into(),
+            url: url.into(),
+        });
+    }
+}
+
+type Callback = dyn Fn(Endpoint, &[ValidationWarning]) + Send + Sync;
+
+/// Calls back with the warnings of each response that has any.
+#[derive(Clone)]
+pub(crate) struct WarningHandler(Arc<Callback>);
+
+impl WarningHandler {
+    pub(crate) fn new(
+        callback: impl Fn(Endpoint, &[ValidationWarning]) + Send + Sync + 'static,
+    ) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    pub(crate) fn check(&self, endpoint: Endpoint, warnings: Vec<ValidationWarning>) {
+        if !warnings.is_empty() {
+            (self.0)(endpoint, &warnings);
+        }
+    }
+}
+
+impl fmt::Debug for WarningHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WarningHandler").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{fixtures::event_info, Occurrence};
+
+    fn events() -> GetEventsResponse {
+        let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn search() -> SearchResponse {
+        let json = std::fs::read_to_string("testdata/search-default.json").unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn accepts_fixtures() {
+        assert!(events().validate().is_empty());
+        assert!(search().validate().is_empty());
+        assert!(event_info().validate().is_empty());
+    }
+
+    #[test]
+    fn finds_ids_in_several_buckets() {
+        let mut events = events();
+        let ongoing = events.events[0].clone();
+        events.multiday_ongoing.push(ongoing.clone());
+        assert_eq!(
+            vec![ValidationWarning::DuplicateId {
+                id: ongoing.id.to_string()
+            }],
+            events.validate()
+        );
+    }
+
+    #[test]
+    fn finds_duplicate_search_results() {
+        let mut search = search();
+        let duplicate = search.events[1].clone();
+        search.events.push(duplicate.clone());
+        assert_eq!(
+            vec![ValidationWarning::DuplicateId {
+                id: duplicate.id.to_string()
+            }],
+            search.validate()
+        );
+    }
+
+    #[test]
+    fn finds_empty_names_and_ids() {
+        let mut events = events();
+        events.events[0].name = " ".into();
+        let id = events.events[0].id.to_string();
+        events.events[1].id = "".into();
+        let name = events.events[1].name.clone();
+        assert_eq!(
+            vec![
+                ValidationWarning::EmptyName { id },
+                ValidationWarning::EmptyId { name },
+            ],
+            events.validate()
+        );
+    }
+
+    #[test]
+    fn finds_urls_of_other_events() {
+        let mut search = search();
+        let url = "https://www.checkiday.com/0123456789abcdef/other-day";
+        search.events[0].url = serde_json::from_value(url.into()).unwrap();
+        let id = search.events[0].id.to_string();
+        assert_eq!(
+            vec![ValidationWarning::IdUrlMismatch {
+                id,
+                url: url.into()
+            }],
+            search.validate()
+        );
+    }
+
+    #[test]
+    fn finds_non_positive_lengths() {
+        let mut event = event_info();
+        let occurrences = event.occurrences.as_mut().unwrap();
+        occurrences[1].length = 0;
+        occurrences.push(Occurrence {
+            length: -2,
+            ..occurrences[0].clone()
+        });
+        assert_eq!(
+            vec![
+                ValidationWarning::NonPositiveLength {
+                    index: 1,
+                    length: 0
+                },
+                ValidationWarning::NonPositiveLength {
+                    index: 7,
+                    length: -2
+                },
+            ],
+            event.validate()
+        );
+    }
+
+    #[test]
+    fn finds_reversed_years() {
+        let mut event = event_info();
+        event.alternate_names[0].last_year = Some(2000);
+        event.patterns.as_mut().unwrap()[0].last_year = Some(2001);
+        assert_eq!(
+            vec![
+                ValidationWarning::YearsReversed {
+                    first_year: 2005,
+                    last_year: 2000
+                },
+                ValidationWarning::YearsReversed {
+                    first_year: 2002,
+                    last_year: 2001
+                },
+            ],
+            event.validate()
+        );
+    }
+
+    #[test]
+    fn describes_warnings() {
+        assert_eq!(
+            "Occurrence 3 has length 0",
+            ValidationWarning::NonPositiveLength {
+                index: 3,
+                length: 0
+            }
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+/// The query parameters of a request, in the order they are sent.
+///
+/// Parameters set by the crate are added with [`insert_unique`], then
+/// caller-provided ones with [`extend_extra`]. Caller-provided parameters may
+/// only replace crate-set ones when explicitly allowed.
+///
+/// [`insert_unique`]: QueryParams::insert_unique
+/// [`extend_extra`]: QueryParams::extend_extra
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct QueryParams(Vec<(String, String)>);
+
+impl QueryParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a parameter set by the crate. Each key must only be set once.
+    pub fn insert_unique(&mut self, key: &str, value: impl Into<String>) {
+        debug_assert!(!self.contains(key), "query parameter {key:?} set twice");
+        self.0.push((key.into(), value.into()));
+    }
+
+    /// Adds caller-provided parameters, sorted by key.
+    ///
+    /// Keys in `reserved` (the ones the crate models) are rejected, unless
+    /// `allow_override` is set, in which case they replace the crate-set
+    /// value. `apikey` is always rejected, so it can't end up in a URL. On
+    /// failure, returns the first rejected key.
+    pub fn extend_extra(
+        &mut self,
+        params: &HashMap<String, String>,
+        reserved: &[&str],
+        allow_override: bool,
+    ) -> Result<(), String> {
+        let mut params: Vec<(&String, &String)> = params.iter().collect();
+        params.sort();
+        for (key, value) in params {
+            if key.eq_ignore_ascii_case("apikey")
+                || (reserved.contains(&key.as_str()) && !allow_override)
+            {
+                return Err(key.clone());
+            }
+            match self.0.iter_mut().find(|(k, _)| k == key) {
+                Some((_, v)) => *v = value.clone(),
+                None => self.0.push((key.clone(), value.clone())),
+            }
+        }
+        Ok(())
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.0.iter().any(|(k, _)| k == key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_insertion_order() {
+        let mut params = QueryParams::new();
+        params.insert_unique("query", "zucchini");
+        params.insert_unique("adult", "false");
+
+        assert_eq!(
+            vec![("query", "zucchini"), ("adult", "false")],
+            params.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "query parameter \"adult\" set twice")]
+    #[cfg(debug_assertions)]
+    fn rejects_duplicates() {
+        let mut params = QueryParams::new();
+        params.insert_unique("adult", "false");
+        params.insert_unique("adult", "true");
+    }
+
+    #[test]
+    fn sorts_extra_params() {
+        let mut params = QueryParams::new();
+        params.insert_unique("query", "zucchini");
+        params
+            .extend_extra(
+                &HashMap::from([("page".into(), "2".into()), ("lang".into(), "fr".into())]),
+                &["query"],
+                false,
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![("query", "zucchini"), ("lang", "fr"), ("page", "2")],
+            params.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rejects_reserved_keys() {
+        let extra = HashMap::from([("query".into(), "pumpkin".into())]);
+        let mut params = QueryParams::new();
+        params.insert_unique("query", "zucchini");
+
+        assert_eq!(
+            Err("query".into()),
+            params.extend_extra(&extra, &["query"], false)
+        );
+        // Reserved even when the crate didn't set it this time.
+        assert_eq!(
+            Err("query".into()),
+            QueryParams::new().extend_extra(&extra, &["query"], false)
+        );
+    }
+
+    #[test]
+    fn overrides_reserved_keys_when_allowed() {
+        let mut params = QueryParams::new();
+        params.insert_unique("query", "zucchini");
+        params.insert_unique("adult", "false");
+        params
+            .extend_extra(
+                &HashMap::from([("query".into(), "pumpkin".into())]),
+                &["query", "adult"],
+                true,
+            )
+            .unwrap();
+
+        assert_eq!(
+            vec![("query", "pumpkin"), ("adult", "false")],
+            params.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn always_rejects_api_key() {
+        let mut params = QueryParams::new();
+        for key in ["apikey", "ApiKey"] {
+            let extra = HashMap::from([(key.into(), "secret".into())]);
+            assert_eq!(Err(key.into()), params.extend_extra(&extra, &[], true));
+        }
+    }
+}
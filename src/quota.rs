@@ -0,0 +1,71 @@
+use std::{fmt, sync::Arc};
+
+use crate::model::RateLimit;
+
+/// Calls back when the remaining monthly quota drops below a threshold.
+#[derive(Clone)]
+pub(crate) struct LowQuotaAlert {
+    threshold: i32,
+    callback: Arc<dyn Fn(RateLimit) + Send + Sync>,
+}
+
+impl fmt::Debug for LowQuotaAlert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LowQuotaAlert")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LowQuotaAlert {
+    pub fn new(threshold: i32, callback: impl Fn(RateLimit) + Send + Sync + 'static) -> Self {
+        Self {
+            threshold,
+            callback: Arc::new(callback),
+        }
+    }
+
+    /// Calls back if `current` is below the threshold while `previous`
+    /// wasn't, or was unknown.
+    pub fn check(&self, previous: RateLimit, current: RateLimit) {
+        let was_low = previous != RateLimit::default() && previous.remaining_month < self.threshold;
+        if current.remaining_month < self.threshold && !was_low {
+            (self.callback)(current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    fn remaining(remaining_month: i32) -> RateLimit {
+        RateLimit {
+            limit_month: 100,
+            remaining_month,
+        }
+    }
+
+    #[test]
+    fn fires_when_crossing_the_threshold() {
+        let calls = Arc::new(Mutex::new(vec![]));
+        let alert = LowQuotaAlert::new(50, {
+            let calls = calls.clone();
+            move |rate_limit| calls.lock().unwrap().push(rate_limit.remaining_month)
+        });
+
+        alert.check(RateLimit::default(), remaining(60));
+        alert.check(remaining(60), remaining(50));
+        alert.check(remaining(50), remaining(49));
+        alert.check(remaining(49), remaining(48));
+        // The quota was reset, then dropped again.
+        alert.check(remaining(48), remaining(100));
+        alert.check(remaining(100), remaining(10));
+        // The first known quota is already low.
+        alert.check(RateLimit::default(), remaining(5));
+
+        assert_eq!(vec![49, 10, 5], *calls.lock().unwrap());
+    }
+}
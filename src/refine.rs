@@ -0,0 +1,171 @@
+#[cfg(not(feature = "chrono"))]
+use std::time::UNIX_EPOCH;
+use std::{collections::HashSet, time::SystemTime};
+
+use crate::{model, Error, HolidayEventApi};
+
+static MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The words appended by [`RefineStrategy::AppendKinds`](model::RefineStrategy::AppendKinds).
+static KINDS: [&str; 3] = ["Day", "Week", "Month"];
+
+impl HolidayEventApi {
+    /// Searches for Events, narrowing the query with each of `strategies` in
+    /// turn while the API reports too many results (see
+    /// [`Error::is_too_many_results`]).
+    ///
+    /// The returned [`RefinedSearch::refinement`](model::RefinedSearch::refinement)
+    /// tells which strategy succeeded, if any was needed. Each attempt costs
+    /// quota: one request for the original query, plus one per strategy tried,
+    /// or up to three for [`RefineStrategy::AppendKinds`](model::RefineStrategy::AppendKinds).
+    /// Fails with the last error once every strategy is exhausted, or with the
+    /// first error unrelated to the amount of results.
+    pub async fn search_refined(
+        &self,
+        query: &str,
+        adult: Option<bool>,
+        strategies: &[model::RefineStrategy],
+    ) -> Result<model::RefinedSearch, Error> {
+        let mut error = match self.search_query(query, adult).await {
+            Ok(response) => {
+                return Ok(model::RefinedSearch {
+                    response,
+                    refinement: None,
+                })
+            }
+            Err(e) => e,
+        };
+
+        for &strategy in strategies {
+            if !error.is_too_many_results() {
+                break;
+            }
+            let (refined, result) = match strategy {
+                model::RefineStrategy::AppendMonth => {
+                    let refined = format!("{query} {}", MONTHS[month_index(self.now())]);
+                    let result = self.search_query(&refined, adult).await;
+                    (refined, result)
+                }
+                model::RefineStrategy::AppendKinds => {
+                    let queries = KINDS.map(|kind| format!("{query} {kind}"));
+                    (
+                        queries.join(" OR "),
+                        self.search_any_query(&queries, adult).await,
+                    )
+                }
+            };
+            match result {
+                Ok(response) => {
+                    return Ok(model::RefinedSearch {
+                        response,
+                        refinement: Some(model::Refinement {
+                            strategy,
+                            query: refined,
+                        }),
+                    })
+                }
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+
+    async fn search_query(
+        &self,
+        query: &str,
+        adult: Option<bool>,
+    ) -> Result<model::SearchResponse, Error> {
+        self.search(model::SearchRequest {
+            query: query.into(),
+            adult,
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Searches each of `queries`, failing if any does, and merges the Events
+    /// found by any of them.
+    async fn search_any_query(
+        &self,
+        queries: &[String],
+        adult: Option<bool>,
+    ) -> Result<model::SearchResponse, Error> {
+        let mut combined: Option<model::SearchResponse> = None;
+        for query in queries {
+            let response = self.search_query(query, adult).await?;
+            match &mut combined {
+                None => combined = Some(response),
+                Some(combined) => {
+                    let mut seen: HashSet<String> =
+                        combined.events.iter().map(|e| e.id.clone()).collect();
+                    combined.events.extend(
+                        response
+                            .events
+                            .into_iter()
+                            .filter(|event| seen.insert(event.id.clone())),
+                    );
+                    combined.rate_limit = response.rate_limit;
+                }
+            }
+        }
+        combined.ok_or_else(|| Error::MissingQuery(self.messages.missing_query.clone()))
+    }
+}
+
+/// The zero-based month of `time`, in UTC.
+#[cfg(feature = "chrono")]
+fn month_index(time: SystemTime) -> usize {
+    use chrono::Datelike;
+
+    chrono::DateTime::<chrono::Utc>::from(time).month0() as usize
+}
+
+/// The zero-based month of `time`, in UTC.
+#[cfg(not(feature = "chrono"))]
+fn month_index(time: SystemTime) -> usize {
+    let days = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400);
+    // Days since 0000-03-01, in 400-year eras (Howard Hinnant's civil_from_days).
+    let day_of_era = (days + 719_468) % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    ((month_from_march + 2) % 12) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn at(secs: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn finds_the_month() {
+        assert_eq!("January", MONTHS[month_index(at(0))]);
+        // 2024-02-29T23:59:59Z
+        assert_eq!("February", MONTHS[month_index(at(1_709_251_199))]);
+        // 2024-03-01T00:00:00Z
+        assert_eq!("March", MONTHS[month_index(at(1_709_251_200))]);
+        // 2025-12-31T12:00:00Z
+        assert_eq!("December", MONTHS[month_index(at(1_767_182_400))]);
+    }
+}
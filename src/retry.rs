@@ -0,0 +1,25 @@
+use std::{fmt, sync::Arc};
+
+use crate::Error;
+
+type Predicate = dyn Fn(&Error) -> bool + Send + Sync;
+
+/// Decides which failed requests to retry.
+#[derive(Clone)]
+pub(crate) struct RetryIf(Arc<Predicate>);
+
+impl RetryIf {
+    pub fn new(predicate: Arc<Predicate>) -> Self {
+        Self(predicate)
+    }
+
+    pub fn should_retry(&self, error: &Error) -> bool {
+        (self.0)(error)
+    }
+}
+
+impl fmt::Debug for RetryIf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryIf").finish_non_exhaustive()
+    }
+}
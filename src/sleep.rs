@@ -0,0 +1,120 @@
+//! Waiting without tying the crate to one async runtime.
+//!
+//! Everything that waits (pacing requests and delaying retries) goes through
+//! the client's [`Sleeper`], chosen when the client is built: set with
+//! [`ClientBuilder::sleeper`](crate::ClientBuilder::sleeper), or else
+//! [`TokioSleeper`] with the `tokio-time` feature and [`FuturesTimerSleeper`]
+//! with the `async-std-compat` feature.
+
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+
+/// A future that completes once a [`Sleeper`] is done waiting.
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Waits for a while, e.g. on the timer of the async runtime in use.
+pub trait Sleeper: Debug + Send + Sync {
+    /// Waits for `duration`.
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// Sleeps on the Tokio timer, so it must run within a Tokio runtime with the
+/// time driver enabled.
+#[cfg(feature = "tokio-time")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+#[cfg(feature = "tokio-time")]
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(async move { tokio::time::sleep(duration).await })
+    }
+}
+
+/// Sleeps on a [`futures_timer::Delay`], which works with any executor, e.g.
+/// async-std or smol.
+#[cfg(feature = "async-std-compat")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FuturesTimerSleeper;
+
+#[cfg(feature = "async-std-compat")]
+impl Sleeper for FuturesTimerSleeper {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(futures_timer::Delay::new(duration))
+    }
+}
+
+/// The sleeper enabled by the crate's features, if any. Tokio's is preferred
+/// when both are enabled.
+pub(crate) fn default_sleeper() -> Option<std::sync::Arc<dyn Sleeper>> {
+    #[cfg(feature = "tokio-time")]
+    return Some(std::sync::Arc::new(TokioSleeper));
+    #[cfg(all(feature = "async-std-compat", not(feature = "tokio-time")))]
+    return Some(std::sync::Arc::new(FuturesTimerSleeper));
+    #[cfg(not(any(feature = "tokio-time", feature = "async-std-compat")))]
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "async-std-compat")]
+    use std::{
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+        time::Instant,
+    };
+
+    use super::*;
+
+    /// Wakes by unparking the thread polling the future.
+    #[cfg(feature = "async-std-compat")]
+    struct Unpark(Thread);
+
+    #[cfg(feature = "async-std-compat")]
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Runs `future` to completion without any async runtime.
+    #[cfg(feature = "async-std-compat")]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "async-std-compat")]
+    fn sleeps_without_a_runtime() {
+        let started = Instant::now();
+        block_on(FuturesTimerSleeper.sleep(Duration::from_millis(20)));
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tokio-time")]
+    async fn sleeps_on_tokio() {
+        let started = tokio::time::Instant::now();
+        TokioSleeper.sleep(Duration::from_millis(20)).await;
+        assert!(started.elapsed() >= Duration::from_millis(20));
+    }
+
+    #[test]
+    fn prefers_tokio() {
+        let sleeper = format!("{:?}", default_sleeper());
+        #[cfg(feature = "tokio-time")]
+        assert_eq!("Some(TokioSleeper)", sleeper);
+        #[cfg(all(feature = "async-std-compat", not(feature = "tokio-time")))]
+        assert_eq!("Some(FuturesTimerSleeper)", sleeper);
+        #[cfg(not(any(feature = "tokio-time", feature = "async-std-compat")))]
+        assert_eq!("None", sleeper);
+    }
+}
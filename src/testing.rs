@@ -0,0 +1,50 @@
+//! Helpers for testing code that uses the client.
+
+use std::{sync::Mutex, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::{sleep::Sleep, Clock, Sleeper};
+
+mod chaos;
+
+pub use chaos::{ChaosClient, FailurePolicy};
+
+/// A [`Clock`] that only moves when told to, or when slept on: as a
+/// [`Sleeper`], sleeping advances it instantly.
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    /// A clock stopped at `now`.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap() = now;
+    }
+
+    /// Moves the current time forward (or backward, if negative).
+    pub fn advance(&self, by: TimeDelta) {
+        *self.now.lock().unwrap() += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
+
+impl Sleeper for ManualClock {
+    fn sleep(&self, duration: Duration) -> Sleep {
+        self.advance(TimeDelta::from_std(duration).unwrap_or(TimeDelta::MAX));
+        Box::pin(std::future::ready(()))
+    }
+}
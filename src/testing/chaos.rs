@@ -0,0 +1,288 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use reqwest::StatusCode;
+
+use crate::{error, model, Endpoint, Error, HolidayEventApi};
+
+/// The failures a [`ChaosClient`] injects. The default injects none.
+#[derive(Debug, Clone, Default)]
+pub struct FailurePolicy {
+    fail_every: usize,
+    latency: Duration,
+    statuses: HashMap<Endpoint, u16>,
+    truncated: HashSet<Endpoint>,
+}
+
+impl FailurePolicy {
+    /// A policy that injects no failures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fails every `n`th request, across endpoints, with a 503 Service
+    /// Unavailable. 0 turns this off.
+    pub fn fail_every(mut self, n: usize) -> Self {
+        self.fail_every = n;
+        self
+    }
+
+    /// Waits `latency` on the client's [`Sleeper`](crate::Sleeper) before
+    /// every request, including failed ones. Without one, requests fail with
+    /// [`Error::MissingSleeper`].
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Responds to every request to `endpoint` with `status`, e.g. 429 or 500.
+    pub fn status(mut self, endpoint: Endpoint, status: u16) -> Self {
+        self.statuses.insert(endpoint, status);
+        self
+    }
+
+    /// Responds to every request to `endpoint` with a body cut off midway.
+    pub fn truncate(mut self, endpoint: Endpoint) -> Self {
+        self.truncated.insert(endpoint);
+        self
+    }
+}
+
+/// Wraps a client to simulate API outages, e.g. in integration tests.
+///
+/// Injected failures are returned as the errors the client returns for such
+/// responses, without sending a request. Other requests pass through to the
+/// wrapped client unchanged.
+#[derive(Debug)]
+pub struct ChaosClient {
+    api: HolidayEventApi,
+    policy: FailurePolicy,
+    requests: AtomicUsize,
+}
+
+impl ChaosClient {
+    /// Wraps `api`, injecting no failures until given a
+    /// [`policy`](Self::policy).
+    pub fn wrapping(api: HolidayEventApi) -> Self {
+        Self {
+            api,
+            policy: FailurePolicy::default(),
+            requests: AtomicUsize::new(0),
+        }
+    }
+
+    /// Injects the failures of `policy`.
+    pub fn policy(mut self, policy: FailurePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &HolidayEventApi {
+        &self.api
+    }
+
+    /// How many requests were made, including failed ones.
+    pub fn requests(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+
+    /// Gets the Events for the provided Date, unless failing.
+    pub async fn get_events(
+        &self,
+        request: model::GetEventsRequest,
+    ) -> Result<model::GetEventsResponse, Error> {
+        self.inject(Endpoint::Events).await?;
+        self.api.get_events(request).await
+    }
+
+    /// Gets the Event Info for the provided Event, unless failing.
+    pub async fn get_event_info(
+        &self,
+        request: model::GetEventInfoRequest,
+    ) -> Result<model::GetEventInfoResponse, Error> {
+        self.inject(Endpoint::EventInfo).await?;
+        self.api.get_event_info(request).await
+    }
+
+    /// Searches for Events with the given criteria, unless failing.
+    pub async fn search(
+        &self,
+        request: model::SearchRequest,
+    ) -> Result<model::SearchResponse, Error> {
+        self.inject(Endpoint::Search).await?;
+        self.api.search(request).await
+    }
+
+    async fn inject(&self, endpoint: Endpoint) -> Result<(), Error> {
+        if !self.policy.latency.is_zero() {
+            self.api.sleep(self.policy.latency).await?;
+        }
+        let count = self.requests.fetch_add(1, Ordering::SeqCst) + 1;
+        if let Some(&status) = self.policy.statuses.get(&endpoint) {
+            return Err(status_error(endpoint, status));
+        }
+        if self.policy.fail_every > 0 && count % self.policy.fail_every == 0 {
+            return Err(status_error(endpoint, 503));
+        }
+        if self.policy.truncated.contains(&endpoint) {
+            let truncated = r#"{"adult":false,"events":[{"id":"#;
+            let e = serde_json::from_str::<serde_json::Value>(truncated).unwrap_err();
+            return Err(Error::Parse(e));
+        }
+        Ok(())
+    }
+}
+
+/// The error the client returns for a response with `status`, no body, and
+/// no rate limit headers.
+fn status_error(endpoint: Endpoint, status: u16) -> Error {
+    let message = StatusCode::from_u16(status)
+        .ok()
+        .and_then(|status| status.canonical_reason())
+        .unwrap_or_default()
+        .to_string();
+    if status == 429 {
+        Error::QuotaExhausted { message }
+    } else {
+        error::api_error(endpoint, status, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use mockito::{Matcher, Server};
+
+    use super::*;
+    use crate::{testing::ManualClock, Clock};
+
+    macro_rules! aw {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    /// A client that must not send any request.
+    fn offline() -> ChaosClient {
+        ChaosClient::wrapping(
+            HolidayEventApi::builder("abc123")
+                .base_url("http://127.0.0.1:9/")
+                .build()
+                .unwrap(),
+        )
+    }
+
+    fn event_info() -> model::GetEventInfoRequest {
+        model::GetEventInfoRequest {
+            id: "f90b893ea04939d7456f30c54f68d7b4".into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn passes_through_unchanged() {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("GET", "/event")
+            .match_query(Matcher::Any)
+            .with_header("x-ratelimit-limit-month", "100")
+            .with_header("x-ratelimit-remaining-month", "88")
+            .with_body_from_file("testdata/getEventInfo-default.json")
+            .expect(2)
+            .create();
+
+        let api = HolidayEventApi::new_internal("abc123", &server.url()).unwrap();
+        let chaos = ChaosClient::wrapping(api.clone());
+        let expected = aw!(api.get_event_info(event_info())).unwrap();
+        let actual = aw!(chaos.get_event_info(event_info())).unwrap();
+        assert_eq!(format!("{expected:?}"), format!("{actual:?}"));
+        assert_eq!(1, chaos.requests());
+
+        mock.assert();
+    }
+
+    #[test]
+    fn fails_every_nth_request() {
+        let mut server = Server::new();
+
+        let mock = server
+            .mock("GET", "/events")
+            .match_query(Matcher::Any)
+            .with_body_from_file("testdata/getEvents-default.json")
+            .expect(5)
+            .create();
+
+        let api = HolidayEventApi::new_internal("abc123", &server.url()).unwrap();
+        let chaos = ChaosClient::wrapping(api).policy(FailurePolicy::new().fail_every(3));
+        let failed: Vec<bool> = (0..6)
+            .map(|_| aw!(chaos.get_events(Default::default())).is_err())
+            .collect();
+        assert_eq!(vec![false, false, true, false, false, true], failed);
+        assert!(aw!(chaos.get_events(Default::default())).is_ok());
+
+        mock.assert();
+    }
+
+    #[test]
+    fn fails_endpoints_with_statuses() {
+        let chaos = offline().policy(
+            FailurePolicy::new()
+                .status(Endpoint::Events, 500)
+                .status(Endpoint::Search, 429),
+        );
+
+        match aw!(chaos.get_events(Default::default())).unwrap_err() {
+            Error::Api { status, message } => {
+                assert_eq!(500, status);
+                assert_eq!("Internal Server Error", message);
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+        match aw!(chaos.search(model::SearchRequest {
+            query: "zucchini".into(),
+            ..Default::default()
+        }))
+        .unwrap_err()
+        {
+            Error::QuotaExhausted { message } => assert_eq!("Too Many Requests", message),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[test]
+    fn truncates_responses() {
+        let chaos = offline().policy(FailurePolicy::new().truncate(Endpoint::EventInfo));
+
+        match aw!(chaos.get_event_info(event_info())).unwrap_err() {
+            Error::Parse(e) => assert!(e.is_eof()),
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
+
+    #[test]
+    fn adds_latency() {
+        let start = chrono::DateTime::UNIX_EPOCH;
+        let clock = Arc::new(ManualClock::new(start));
+        let api = HolidayEventApi::builder("abc123")
+            .base_url("http://127.0.0.1:9/")
+            .sleeper(clock.clone())
+            .build()
+            .unwrap();
+        let chaos = ChaosClient::wrapping(api).policy(
+            FailurePolicy::new()
+                .latency(Duration::from_secs(2))
+                .status(Endpoint::Events, 500),
+        );
+
+        assert!(aw!(chaos.get_events(Default::default())).is_err());
+        assert!(aw!(chaos.get_events(Default::default())).is_err());
+        assert_eq!(start + chrono::TimeDelta::seconds(4), clock.now());
+        assert_eq!(2, chaos.requests());
+    }
+}
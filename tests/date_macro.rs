@@ -0,0 +1,9 @@
+//! Checks that [`holiday_event_api::date!`] rejects invalid dates at compile
+//! time.
+#![cfg(feature = "chrono")]
+
+#[test]
+fn rejects_invalid_dates() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/invalid_date.rs");
+}
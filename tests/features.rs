@@ -0,0 +1,226 @@
+//! Exercises the public API of each Cargo feature, so every feature keeps
+//! compiling on its own. CI runs this once per feature, e.g.
+//! `cargo test --no-default-features --features ical --test features`.
+//!
+//! `typed-urls` and `chrono-tz` are not additive: they change the types of
+//! URL fields and `GetEventsRequest::timezone`, so their tests use the changed
+//! types.
+
+#[cfg(any(feature = "chrono", feature = "ical"))]
+use holiday_event_api::model::{EventInfo, GetEventInfoResponse};
+use holiday_event_api::{model::GetEventsResponse, HolidayEventApi};
+
+fn events() -> GetEventsResponse {
+    let json = std::fs::read_to_string("testdata/getEvents-default.json").unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[cfg(any(feature = "chrono", feature = "ical"))]
+fn event_info() -> EventInfo {
+    let json = std::fs::read_to_string("testdata/getEventInfo-default.json").unwrap();
+    serde_json::from_str::<GetEventInfoResponse>(&json)
+        .unwrap()
+        .event
+}
+
+#[test]
+fn bare_client() {
+    assert!(HolidayEventApi::new("abc123").is_ok());
+    assert!(HolidayEventApi::builder("abc123").build().is_ok());
+    assert_eq!(5, events().unique_events().len());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn chrono() {
+    use holiday_event_api::model::OccurrenceRange;
+
+    assert_eq!(
+        Some(chrono::NaiveDate::from_ymd_opt(2025, 5, 5).unwrap()),
+        events().date.parse_flexible().date()
+    );
+    let range = OccurrenceRange::RelativeToNow {
+        back: 1,
+        forward: 1,
+    };
+    assert_eq!((2024, 2026), range.resolve(2025).unwrap());
+    assert_eq!(2, event_info().occurrences_between(2020, 2021).len());
+    assert_eq!("08/08/2020", holiday_event_api::date!("08/08/2020"));
+}
+
+#[test]
+#[cfg(feature = "chrono-tz")]
+fn chrono_tz() {
+    use holiday_event_api::model::{DateOrTimestamp, GetEventsRequest};
+
+    let date = DateOrTimestamp::Timestamp(0);
+    assert_eq!(
+        "1969-12-31",
+        date.format("%Y-%m-%d", Some("America/Chicago")).unwrap()
+    );
+    let request = GetEventsRequest {
+        timezone: Some(chrono_tz::America::Chicago.into()),
+        ..Default::default()
+    };
+    assert_eq!("America/Chicago", request.timezone.unwrap().as_str());
+}
+
+#[test]
+#[cfg(feature = "ical")]
+fn ical() {
+    assert!(events().to_ical().contains("BEGIN:VEVENT"));
+    assert!(event_info().to_ical().contains("BEGIN:VEVENT"));
+}
+
+#[test]
+#[cfg(feature = "typed-urls")]
+fn typed_urls() {
+    let url = &events().events[0].url;
+    assert_eq!(Some("www.checkiday.com"), url.url().unwrap().host_str());
+}
+
+#[test]
+#[cfg(feature = "html-sanitize")]
+fn html_sanitize() {
+    use holiday_event_api::model::RichText;
+
+    let text = RichText {
+        text: None,
+        html: Some("<p onclick=\"x()\">Hi<script>x()</script></p>".into()),
+        markdown: None,
+    };
+    assert_eq!(Some("<p>Hi</p>".into()), text.sanitized_html());
+}
+
+#[test]
+#[cfg(feature = "markdown")]
+fn markdown() {
+    use holiday_event_api::model::RichText;
+
+    let text = RichText {
+        text: None,
+        html: Some("<p>Hi <a href=\"https://example.com\">there</a></p>".into()),
+        markdown: None,
+    };
+    assert_eq!(
+        Some("Hi [there](https://example.com)".into()),
+        text.to_markdown()
+    );
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn unicode() {
+    let event = &events().events[0];
+    assert!(event.matches_name(&event.name.to_uppercase()));
+}
+
+#[test]
+#[cfg(feature = "cli")]
+fn cli() {
+    let events = events();
+    assert!(events.to_table().contains(&events.events[0].name));
+}
+
+#[test]
+#[cfg(feature = "disk-cache")]
+fn disk_cache() {
+    use std::{sync::Arc, time::Duration};
+
+    use holiday_event_api::DirCacheStore;
+
+    let dir =
+        std::env::temp_dir().join(format!("holiday_event_api-features-{}", std::process::id()));
+    let store = Arc::new(DirCacheStore::new(&dir).unwrap());
+    assert!(HolidayEventApi::builder("abc123")
+        .cache_store(store, Duration::from_secs(60))
+        .build()
+        .is_ok());
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+#[cfg(feature = "arbitrary-precision")]
+fn arbitrary_precision() {
+    use holiday_event_api::model::DateOrTimestamp;
+
+    let date: DateOrTimestamp = serde_json::from_str("1000000000000000000000000000001").unwrap();
+    assert_eq!(
+        DateOrTimestamp::Date("1000000000000000000000000000001".into()),
+        date
+    );
+}
+
+#[test]
+#[cfg(feature = "uuid")]
+fn uuid() {
+    let api = HolidayEventApi::builder("abc123")
+        .request_id_header("X-Trace-Id")
+        .build()
+        .unwrap();
+    assert_eq!(None, api.last_request_id());
+}
+
+#[test]
+#[cfg(feature = "cancellation")]
+fn cancellation() {
+    let token = tokio_util::sync::CancellationToken::new();
+    token.cancel();
+    let api = HolidayEventApi::builder("abc123")
+        .cancellation_token(token)
+        .build()
+        .unwrap();
+    let result = tokio_test::block_on(api.get_events(Default::default()));
+    assert!(matches!(result, Err(holiday_event_api::Error::Cancelled)));
+}
+
+#[test]
+#[cfg(feature = "tokio-time")]
+fn tokio_time() {
+    use holiday_event_api::{Sleeper, TokioSleeper};
+
+    tokio_test::block_on(TokioSleeper.sleep(std::time::Duration::from_millis(1)));
+}
+
+#[test]
+#[cfg(feature = "async-std-compat")]
+fn async_std_compat() {
+    use std::sync::Arc;
+
+    use holiday_event_api::{FuturesTimerSleeper, Sleeper};
+
+    tokio_test::block_on(FuturesTimerSleeper.sleep(std::time::Duration::from_millis(1)));
+    assert!(HolidayEventApi::builder("abc123")
+        .sleeper(Arc::new(FuturesTimerSleeper))
+        .retry_if(Arc::new(|_| true))
+        .build()
+        .is_ok());
+}
+
+#[test]
+#[cfg(feature = "test-support")]
+fn test_support() {
+    use std::sync::Arc;
+
+    use holiday_event_api::testing::ManualClock;
+
+    let clock = Arc::new(ManualClock::new(chrono::DateTime::UNIX_EPOCH));
+    assert!(HolidayEventApi::builder("abc123")
+        .with_clock(clock)
+        .build()
+        .is_ok());
+}
+
+#[test]
+#[cfg(feature = "interning")]
+fn interning() {
+    use holiday_event_api::Interner;
+
+    let interner = Interner::new();
+    let summaries: Vec<_> = events()
+        .events
+        .into_iter()
+        .map(|event| interner.summary(event))
+        .collect();
+    assert_eq!(summaries.len(), interner.len());
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = holiday_event_api::date!("13/40/2020");
+}